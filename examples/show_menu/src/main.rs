@@ -1,13 +1,17 @@
 
 use iced::{
-    widget::{button, container, row, svg},
-    Application, Color, Length, Rectangle,
+    widget::{button, container, row, svg, text},
+    Application, Color, Length, Point, Rectangle,
 };
 use iced_window::{drag_window, menu::*, resize::resize};
 
+/// Where the window opens, the left and top edges need it to resize the window
+const POSITION: (i32, i32) = (100, 100);
+
 fn main() {
     MenuTester::run(iced::Settings {
         window: iced::window::Settings {
+            position: iced::window::Position::Specific(POSITION.0, POSITION.1),
             decorations: false,
             ..iced::window::Settings::default()
         },
@@ -18,6 +22,7 @@ fn main() {
 
 struct MenuTester {
     title: String,
+    window: iced_window::window::Window,
 }
 
 #[derive(Debug, Clone)]
@@ -38,6 +43,8 @@ impl Application for MenuTester {
         (
             Self {
                 title: "Menu Tester".to_string(),
+                window: iced_window::window::Window::new()
+                    .position(Point::new(POSITION.0 as f32, POSITION.1 as f32)),
             },
             iced::Command::none(),
         )
@@ -47,11 +54,13 @@ impl Application for MenuTester {
         self.title.clone()
     }
 
+    fn subscription(&self) -> iced::Subscription<Self::Message> {
+        iced_window::window::Window::subscription().map(Message::WindowEvents)
+    }
+
     fn update(&mut self, message: Self::Message) -> iced::Command<Self::Message> {
         match message {
-            Message::WindowEvents(event) => {
-                return iced_window::window::Window::event_handler(event)
-            }
+            Message::WindowEvents(event) => return self.window.event_handler(event),
         };
         iced::Command::none()
     }
@@ -60,29 +69,37 @@ impl Application for MenuTester {
         let media = MenuTree::with_children(
             button("Media"),
             vec![
-                MenuTree::new(button("Open File")),
-                MenuTree::new(button("Open Recent")),
+                MenuTree::new(button("Open File")).label("Open File"),
+                MenuTree::new(button("Open Recent"))
+                    .label("Open Recent")
+                    .tooltip("Reopen a recently used file"),
             ],
         );
         let playback = MenuTree::with_children(
             button("Media"),
             vec![
-                MenuTree::new(button("Open File")),
-                MenuTree::new(button("Open Recent")),
+                MenuTree::new(button("Open File")).label("Open File"),
+                MenuTree::new(button("Open Recent"))
+                    .label("Open Recent")
+                    .tooltip("Reopen a recently used file"),
             ],
         );
         let audio = MenuTree::with_children(
             button("Media"),
             vec![
-                MenuTree::new(button("Open File")),
-                MenuTree::new(button("Open Recent")),
+                MenuTree::new(button("Open File")).label("Open File"),
+                MenuTree::new(button("Open Recent"))
+                    .label("Open Recent")
+                    .tooltip("Reopen a recently used file"),
             ],
         );
         let subtitle = MenuTree::with_children(
             button("Media"),
             vec![
-                MenuTree::new(button("Open File")),
-                MenuTree::new(button("Open Recent")),
+                MenuTree::new(button("Open File")).label("Open File"),
+                MenuTree::new(button("Open Recent"))
+                    .label("Open Recent")
+                    .tooltip("Reopen a recently used file"),
             ],
         );
 
@@ -91,12 +108,18 @@ impl Application for MenuTester {
         //     theme: menu_theme::Theme::default(),
         // };
 
-        iced_window::window::Window::view(
-            vec![media, playback, audio, subtitle],
-            Message::WindowEvents,
-            Some(&self.title),
-            Some(Color::WHITE),
-        )
-        .into()
+        self.window
+            .view(
+                vec![media, playback, audio, subtitle],
+                container(text("Hello from the window content"))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y(),
+                Message::WindowEvents,
+                Some(&self.title),
+                Some(Color::WHITE),
+            )
+            .into()
     }
 }
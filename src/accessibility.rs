@@ -0,0 +1,718 @@
+//! Describe the window chrome to assistive technologies
+//!
+//! The chrome is described as a tree of [`Node`]s, the roles and states follow
+//! the ones used by AccessKit, so a flattened tree from [`Node::flatten`] can be
+//! turned into an AccessKit tree update by the host application.
+//!
+//! The open menus live in the widget state of a [`MenuBar`], they can be
+//! retrieved with the [`open_menus`] operation. The paths are tagged with the
+//! [`Id`] of their menu bar, the menu bar and the system menu of a [`TitleBar`]
+//! use [`menu_bar_id`] and [`system_menu_id`].
+//!
+//! [`MenuBar`]: crate::menu::MenuBar
+//! [`TitleBar`]: crate::window::TitleBar
+use std::any::Any;
+
+use iced_native::widget::{operation::Outcome, Id, Operation};
+
+use crate::{
+    menu::{MenuBarState, MenuTree},
+    window::{system_menu_items, ButtonLayout, TitleButton},
+};
+
+/// The role of a [`Node`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// The whole window
+    Window,
+    /// The title bar of the window
+    TitleBar,
+    /// A menu bar holding the menu roots
+    MenuBar,
+    /// A menu holding menu items
+    Menu,
+    /// An item of a menu bar or a menu
+    MenuItem,
+    /// An item of a menu that can be checked
+    MenuItemCheckBox,
+    /// A group of nodes, like the draggable title area of a [`DragWindow`]
+    ///
+    /// [`DragWindow`]: crate::drag_window::DragWindow
+    Group,
+    /// A button
+    Button,
+    /// A static text
+    Label,
+    /// An image, like the window icon
+    Image,
+}
+
+/// The state of a [`Node`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    /// Whether the submenu of the node is open, `None` if it has none
+    pub expanded: Option<bool>,
+    /// Whether the node is checked, `None` if it can't be checked
+    pub checked: Option<bool>,
+    /// Whether the node has the focus
+    pub focused: bool,
+    /// Whether the node is disabled
+    pub disabled: bool,
+}
+
+/// A node of the accessibility tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The role of the node
+    pub role: Role,
+    /// The name read out for the node
+    pub name: Option<String>,
+    /// The state of the node
+    pub state: State,
+    /// The children of the node
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given role and name
+    pub fn new(role: Role, name: Option<String>) -> Self {
+        Self {
+            role,
+            name,
+            state: State::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the [`Node`]
+    #[must_use]
+    pub fn with_children(mut self, children: Vec<Node>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Returns the focused [`Node`] if there is one
+    pub fn focused(&self) -> Option<&Node> {
+        if self.state.focused {
+            return Some(self);
+        }
+
+        self.children.iter().find_map(Node::focused)
+    }
+
+    /// Flattens the tree into a list of [`FlatNode`]s
+    ///
+    /// Ids are handed out depth first starting at 1, the root comes first
+    pub fn flatten(&self) -> Vec<FlatNode> {
+        /// inner flattening function
+        fn rec(node: &Node, next_id: &mut u64, flat: &mut Vec<FlatNode>) -> u64 {
+            let id = *next_id;
+            *next_id += 1;
+
+            let position = flat.len();
+            flat.push(FlatNode {
+                id,
+                role: node.role,
+                name: node.name.clone(),
+                state: node.state,
+                children: Vec::new(),
+            });
+
+            let children = node
+                .children
+                .iter()
+                .map(|c| rec(c, next_id, flat))
+                .collect();
+            flat[position].children = children;
+
+            id
+        }
+
+        let mut flat = Vec::new();
+        let _ = rec(self, &mut 1, &mut flat);
+        flat
+    }
+}
+
+/// A [`Node`] referencing its children by id
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatNode {
+    /// The id of the node
+    pub id: u64,
+    /// The role of the node
+    pub role: Role,
+    /// The name read out for the node
+    pub name: Option<String>,
+    /// The state of the node
+    pub state: State,
+    /// The ids of the children of the node
+    pub children: Vec<u64>,
+}
+
+/// Describes the menu roots of a menu bar
+///
+/// `open_path` is the path of the open menus as returned by [`open_menus`],
+/// the first index is the active root, the rest are the active items of each menu
+pub fn menu_bar<Message, Renderer>(
+    menu_roots: &[MenuTree<'_, Message, Renderer>],
+    open_path: Option<&[usize]>,
+) -> Node {
+    /// describes a menu tree as an item of its parent
+    fn item<Message, Renderer>(mt: &MenuTree<'_, Message, Renderer>) -> Node {
+        let role = if mt.checked.is_some() {
+            Role::MenuItemCheckBox
+        } else {
+            Role::MenuItem
+        };
+        let mut node = Node::new(role, mt.label.clone());
+        node.state.checked = mt.checked;
+        if mt.has_menu() {
+            node.state.expanded = Some(false);
+        }
+        node
+    }
+
+    /// describes a menu tree on the open path
+    fn rec<Message, Renderer>(mt: &MenuTree<'_, Message, Renderer>, path: &[usize]) -> Node {
+        let mut node = item(mt);
+        if mt.children.is_empty() {
+            return node;
+        }
+
+        // an empty path means the menu is open without an active item
+        node.state.expanded = Some(true);
+        node.state.focused = path.is_empty();
+
+        let menu = Node::new(Role::Menu, mt.label.clone()).with_children(
+            mt.children
+                .iter()
+                .enumerate()
+                .map(|(i, c)| match path.split_first() {
+                    Some((&active, rest)) if active == i => rec(c, rest),
+                    _ => item(c),
+                })
+                .collect(),
+        );
+
+        node.with_children(vec![menu])
+    }
+
+    let children = menu_roots
+        .iter()
+        .enumerate()
+        .map(|(i, root)| match open_path.and_then(<[usize]>::split_first) {
+            Some((&active, rest)) if active == i => {
+                let mut node = rec(root, rest);
+                // an item without a submenu has the focus
+                if !rest.is_empty() && node.focused().is_none() {
+                    focus_last(&mut node, rest);
+                }
+                node
+            }
+            _ => item(root),
+        })
+        .collect();
+
+    Node::new(Role::MenuBar, None).with_children(children)
+}
+
+/// Focuses the item at the end of `path` inside a described menu root
+///
+/// Nothing is focused when the path doesn't lead to an item, e.g. when it's stale
+fn focus_last(node: &mut Node, path: &[usize]) {
+    let mut target = node;
+    for &i in path {
+        // menu items hold a single menu node, which holds the items
+        let next = target
+            .children
+            .get_mut(0)
+            .and_then(|menu| menu.children.get_mut(i));
+        let Some(next) = next else { return; };
+        target = next;
+    }
+    target.state.focused = true;
+}
+
+/// Describes the draggable title area of a [`DragWindow`]
+///
+/// The area is named after the title, the title and the subtitle are its labels
+///
+/// [`DragWindow`]: crate::drag_window::DragWindow
+pub fn drag_window(title: Option<&str>, subtitle: Option<&str>) -> Node {
+    let labels = [title, subtitle]
+        .into_iter()
+        .flatten()
+        .map(|text| Node::new(Role::Label, Some(text.to_owned())))
+        .collect();
+
+    Node::new(Role::Group, title.map(ToOwned::to_owned)).with_children(labels)
+}
+
+/// Describes a title button, the title buttons are always enabled
+fn title_button(button: TitleButton, maximized: bool) -> Node {
+    let name = match button {
+        TitleButton::Minimize => "Minimize",
+        TitleButton::Maximize if maximized => "Restore",
+        TitleButton::Maximize => "Maximize",
+        TitleButton::Close => "Close",
+    };
+
+    Node::new(Role::Button, Some(name.to_owned()))
+}
+
+/// Describes a window icon that doesn't open the system menu
+pub fn window_icon() -> Node {
+    Node::new(Role::Image, Some("Window icon".to_owned()))
+}
+
+/// Describes the system menu opened from the window icon
+///
+/// The menu is described as a menu bar with a single root, `open_path` is its path
+/// from [`open_menus`], tagged with [`system_menu_id`].
+/// Items that don't apply to the window state are disabled
+pub fn system_menu(maximized: bool, open_path: Option<&[usize]>) -> Node {
+    let name = || Some("System menu".to_owned());
+    let mut root = Node::new(Role::MenuItem, name());
+    root.state.expanded = Some(false);
+
+    if let Some(rest) = open_path.and_then(|path| path.strip_prefix(&[0])) {
+        let active = rest.first().copied();
+        let items = system_menu_items(maximized)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (label, enabled, _))| {
+                let mut node = Node::new(Role::MenuItem, Some(label.to_owned()));
+                node.state.disabled = !enabled;
+                node.state.focused = active == Some(i);
+                node
+            })
+            .collect();
+
+        root.state.expanded = Some(true);
+        root.state.focused = active.is_none();
+        root = root.with_children(vec![Node::new(Role::Menu, name()).with_children(items)]);
+    }
+
+    Node::new(Role::MenuBar, None).with_children(vec![root])
+}
+
+/// Describes the title bar of a [`Window`]
+///
+/// The nodes follow the title bar from left to right, only the title buttons
+/// in `buttons` are described. The title and subtitle describe the title area,
+/// see [`drag_window`]. `icon` describes the window icon, see [`window_icon`]
+/// and [`system_menu`], `menu_bar` the menu bar, see [`menu_bar`].
+/// [`TitleBar::accessibility`] describes a [`TitleBar`] with all of them
+///
+/// [`Window`]: crate::window::Window
+/// [`TitleBar`]: crate::window::TitleBar
+/// [`TitleBar::accessibility`]: crate::window::TitleBar::accessibility
+pub fn title_bar(
+    title: Option<&str>,
+    subtitle: Option<&str>,
+    maximized: bool,
+    buttons: &ButtonLayout,
+    icon: Option<Node>,
+    menu_bar: Option<Node>,
+) -> Node {
+    let button = |&button: &TitleButton| title_button(button, maximized);
+    let children = buttons
+        .left
+        .iter()
+        .map(button)
+        .chain(icon)
+        .chain(menu_bar)
+        .chain([drag_window(title, subtitle)])
+        .chain(buttons.right.iter().map(button))
+        .collect();
+
+    Node::new(Role::TitleBar, None).with_children(children)
+}
+
+/// Describes a whole [`Window`] with its title bar, see [`title_bar`]
+///
+/// [`Window`]: crate::window::Window
+pub fn window(title: Option<&str>, title_bar: Node) -> Node {
+    Node::new(Role::Window, title.map(ToOwned::to_owned)).with_children(vec![title_bar])
+}
+
+/// The [`Id`] of the menu bar a [`TitleBar`] builds from its menu roots
+///
+/// [`TitleBar`]: crate::window::TitleBar
+pub fn menu_bar_id() -> Id {
+    Id::new("title_bar_menu")
+}
+
+/// The [`Id`] of the menu bar holding the system menu of a [`TitleBar`]
+///
+/// [`TitleBar`]: crate::window::TitleBar
+pub fn system_menu_id() -> Id {
+    Id::new("title_bar_system_menu")
+}
+
+/// The open menus of a menu bar, tagged with the [`Id`] of the menu bar if it has one
+pub type OpenPath = (Option<Id>, Vec<usize>);
+
+/// An [`Operation`] that collects the open menu paths of every menu bar
+///
+/// A menu bar without open menus produces no path
+#[derive(Debug, Default)]
+pub struct OpenMenus {
+    paths: Vec<OpenPath>,
+}
+
+/// Produces an [`OpenMenus`] operation
+pub fn open_menus() -> OpenMenus {
+    OpenMenus::default()
+}
+
+impl Operation<Vec<OpenPath>> for OpenMenus {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Vec<OpenPath>>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if let Some(state) = state.downcast_ref::<MenuBarState>() {
+            let path = state.open_path();
+            self.paths.extend(path.map(|path| (id.cloned(), path)));
+        }
+    }
+
+    fn finish(&self) -> Outcome<Vec<OpenPath>> {
+        Outcome::Some(self.paths.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{renderer::Null, widget::Space, Length};
+
+    use super::*;
+    use crate::window::Preset;
+
+    type Tree = MenuTree<'static, (), Null>;
+
+    fn item(label: &str) -> Tree {
+        MenuTree::new(Space::new(Length::Shrink, Length::Shrink)).label(label)
+    }
+
+    fn menu(label: &str, children: Vec<Tree>) -> Tree {
+        MenuTree::with_children(Space::new(Length::Shrink, Length::Shrink), children).label(label)
+    }
+
+    /// File > (New, Recent > (a.txt, b.txt)), Edit > (Wrap [checked])
+    fn roots() -> Vec<Tree> {
+        vec![
+            menu(
+                "File",
+                vec![
+                    item("New"),
+                    menu("Recent", vec![item("a.txt"), item("b.txt")]),
+                ],
+            ),
+            menu("Edit", vec![item("Wrap").checked(true)]),
+        ]
+    }
+
+    fn focused_name(node: &Node) -> Option<&str> {
+        node.focused().and_then(|n| n.name.as_deref())
+    }
+
+    #[test]
+    fn closed_menu_bar() {
+        let node = menu_bar(&roots(), None);
+
+        assert_eq!(node.role, Role::MenuBar);
+        assert_eq!(node.children.len(), 2);
+        for root in &node.children {
+            assert_eq!(root.role, Role::MenuItem);
+            assert_eq!(root.state.expanded, Some(false));
+            assert!(root.children.is_empty());
+        }
+        assert_eq!(node.focused(), None);
+    }
+
+    #[test]
+    fn open_root_is_focused() {
+        let node = menu_bar(&roots(), Some(&[1]));
+        let edit = &node.children[1];
+
+        assert_eq!(edit.state.expanded, Some(true));
+        assert_eq!(focused_name(&node), Some("Edit"));
+
+        let wrap = &edit.children[0].children[0];
+        assert_eq!(edit.children[0].role, Role::Menu);
+        assert_eq!(wrap.role, Role::MenuItemCheckBox);
+        assert_eq!(wrap.state.checked, Some(true));
+    }
+
+    #[test]
+    fn open_path_focuses_the_active_item() {
+        let node = menu_bar(&roots(), Some(&[0, 1, 0]));
+        let recent = &node.children[0].children[0].children[1];
+
+        assert_eq!(recent.state.expanded, Some(true));
+        assert_eq!(focused_name(&node), Some("a.txt"));
+    }
+
+    #[test]
+    fn open_submenu_without_active_item_is_focused() {
+        let node = menu_bar(&roots(), Some(&[0, 1]));
+
+        assert_eq!(focused_name(&node), Some("Recent"));
+    }
+
+    #[test]
+    fn stale_open_path_focuses_nothing() {
+        for path in [&[0, 9][..], &[0, 0, 3], &[0, 1, 7], &[5, 1]] {
+            let node = menu_bar(&roots(), Some(path));
+            assert_eq!(node.focused(), None, "{path:?}");
+        }
+    }
+
+    #[test]
+    fn empty_menu_bar() {
+        let node = menu_bar::<(), Null>(&[], Some(&[0, 1]));
+
+        assert_eq!(node.role, Role::MenuBar);
+        assert!(node.children.is_empty());
+    }
+
+    #[test]
+    fn drag_window_is_named_after_the_title() {
+        let node = drag_window(Some("Notes"), Some("~/notes.txt"));
+
+        assert_eq!(node.role, Role::Group);
+        assert_eq!(node.name.as_deref(), Some("Notes"));
+        let labels = node
+            .children
+            .iter()
+            .map(|n| (n.role, n.name.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                (Role::Label, Some("Notes")),
+                (Role::Label, Some("~/notes.txt"))
+            ]
+        );
+
+        assert!(drag_window(None, None).children.is_empty());
+    }
+
+    #[test]
+    fn title_bar_holds_the_menu_bar_title_and_buttons() {
+        let buttons = Preset::Windows.layout();
+        let node = title_bar(
+            Some("Notes"),
+            Some("~/notes.txt"),
+            true,
+            &buttons,
+            None,
+            Some(menu_bar(&roots(), None)),
+        );
+
+        assert_eq!(node.role, Role::TitleBar);
+        let children = node
+            .children
+            .iter()
+            .map(|n| (n.role, n.name.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            children,
+            [
+                (Role::MenuBar, None),
+                (Role::Group, Some("Notes")),
+                (Role::Button, Some("Minimize")),
+                (Role::Button, Some("Restore")),
+                (Role::Button, Some("Close")),
+            ]
+        );
+        assert_eq!(node.children[1].children.len(), 2);
+        assert_eq!(
+            node.children[1].children[1].name.as_deref(),
+            Some("~/notes.txt")
+        );
+    }
+
+    /// The roles and names of the children of a title bar built from `buttons`
+    fn title_bar_children(buttons: &ButtonLayout, maximized: bool) -> Vec<(Role, Option<String>)> {
+        title_bar(
+            Some("Notes"),
+            None,
+            maximized,
+            buttons,
+            None,
+            Some(menu_bar(&roots(), None)),
+        )
+        .children
+        .into_iter()
+        .map(|n| (n.role, n.name))
+        .collect()
+    }
+
+    fn button(name: &str) -> (Role, Option<String>) {
+        (Role::Button, Some(name.to_owned()))
+    }
+
+    #[test]
+    fn gnome_title_bar_only_has_close() {
+        assert_eq!(
+            title_bar_children(&Preset::Gnome.layout(), false),
+            [
+                (Role::MenuBar, None),
+                (Role::Group, Some("Notes".to_owned())),
+                button("Close"),
+            ]
+        );
+    }
+
+    #[test]
+    fn mac_os_buttons_come_before_the_menu_bar() {
+        assert_eq!(
+            title_bar_children(&Preset::MacOs.layout(), false),
+            [
+                button("Close"),
+                button("Minimize"),
+                button("Maximize"),
+                (Role::MenuBar, None),
+                (Role::Group, Some("Notes".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn parsed_layout_splits_the_buttons() {
+        let buttons = ButtonLayout::parse("close:minimize,maximize");
+
+        assert_eq!(
+            title_bar_children(&buttons, true),
+            [
+                button("Close"),
+                (Role::MenuBar, None),
+                (Role::Group, Some("Notes".to_owned())),
+                button("Minimize"),
+                button("Restore"),
+            ]
+        );
+        assert!(title_bar_children(&ButtonLayout::parse(":"), false)
+            .iter()
+            .all(|(role, _)| *role != Role::Button));
+    }
+
+    #[test]
+    fn flatten_hands_out_ids_depth_first() {
+        let buttons = Preset::Windows.layout();
+        let title_bar = title_bar(Some("Notes"), None, false, &buttons, None, None);
+        let flat = window(Some("Notes"), title_bar).flatten();
+
+        assert_eq!(flat[0].role, Role::Window);
+        assert_eq!(flat[0].children, [2]);
+        assert_eq!(flat[1].role, Role::TitleBar);
+        for (i, node) in flat.iter().enumerate() {
+            assert_eq!(node.id, i as u64 + 1);
+        }
+    }
+
+    #[test]
+    fn title_bar_describes_the_icon() {
+        let buttons = ButtonLayout::parse(":close");
+        let children = |icon| {
+            title_bar(Some("Notes"), None, false, &buttons, Some(icon), None)
+                .children
+                .into_iter()
+                .map(|n| (n.role, n.name))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            children(window_icon()),
+            [
+                (Role::Image, Some("Window icon".to_owned())),
+                (Role::Group, Some("Notes".to_owned())),
+                button("Close"),
+            ]
+        );
+        assert_eq!(children(system_menu(false, None))[0], (Role::MenuBar, None));
+    }
+
+    #[test]
+    fn system_menu_disables_what_doesnt_apply() {
+        let node = system_menu(false, Some(&[0, 3]));
+        let root = &node.children[0];
+        assert_eq!(root.name.as_deref(), Some("System menu"));
+        assert_eq!(root.state.expanded, Some(true));
+
+        let items = root.children[0]
+            .children
+            .iter()
+            .map(|n| (n.name.as_deref(), n.state.disabled))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (Some("Restore"), true),
+                (Some("Minimize"), false),
+                (Some("Maximize"), false),
+                (Some("Close"), false),
+            ]
+        );
+        assert_eq!(focused_name(&node), Some("Close"));
+
+        let closed = system_menu(true, None);
+        assert_eq!(closed.children[0].state.expanded, Some(false));
+        assert!(closed.children[0].children.is_empty());
+    }
+
+    fn collect(states: &mut [(Option<Id>, MenuBarState)]) -> Vec<OpenPath> {
+        let mut operation = open_menus();
+        for (id, state) in states {
+            Operation::<Vec<OpenPath>>::custom(&mut operation, state, id.as_ref());
+        }
+        match operation.finish() {
+            Outcome::Some(paths) => paths,
+            _ => panic!("no outcome"),
+        }
+    }
+
+    #[test]
+    fn open_menus_collects_open_paths() {
+        assert!(collect(&mut [(None, MenuBarState::default())]).is_empty());
+        assert_eq!(
+            collect(&mut [(None, MenuBarState::opened(&[1, 0, 2]))]),
+            [(None, vec![1, 0, 2])]
+        );
+        assert_eq!(
+            collect(&mut [(None, MenuBarState::opened(&[1]))]),
+            [(None, vec![1])]
+        );
+    }
+
+    #[test]
+    fn open_menus_tags_paths_with_their_menu_bar() {
+        let paths = collect(&mut [
+            (Some(system_menu_id()), MenuBarState::opened(&[0])),
+            (Some(menu_bar_id()), MenuBarState::default()),
+            (Some(Id::new("other")), MenuBarState::opened(&[2, 1])),
+        ]);
+        assert_eq!(
+            paths,
+            [
+                (Some(system_menu_id()), vec![0]),
+                (Some(Id::new("other")), vec![2, 1]),
+            ]
+        );
+    }
+
+    #[test]
+    fn open_menus_ignores_other_states() {
+        let mut operation = open_menus();
+        Operation::<Vec<OpenPath>>::custom(&mut operation, &mut 5_u32, None);
+
+        assert!(matches!(operation.finish(), Outcome::Some(paths) if paths.is_empty()));
+    }
+}
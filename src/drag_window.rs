@@ -1,21 +1,62 @@
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+
 use iced_graphics::alignment;
 use iced_native::text::Text;
-use iced_native::{layout, renderer, widget::Tree, Layout, Widget};
+use iced_native::widget::{tree, Operation};
+use iced_native::{event, layout, overlay, renderer, widget::Tree, Layout, Widget};
 use iced_native::{mouse, touch, Color, Element, Event, Length, Point, Rectangle, Size};
 
+#[derive(Debug, Default)]
+struct DragWindowState {
+    /// When and where the last press happened
+    last_press: Option<(Instant, Point)>,
+    /// Where the held press happened, until it turns into a drag or a click
+    pressed_at: Option<Point>,
+}
+
+/// Where the title is placed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TitleAlignment {
+    /// At the start of the drag area
+    Left,
+    /// Centered on the window, as long as it fits into the drag area
+    CenterWindow,
+    /// Centered on the drag area
+    #[default]
+    CenterRemaining,
+}
+
 /// An amount of empty space.
 ///
 /// It can be useful if you want to fill some space with nothing.
-#[derive(Debug)]
-pub struct DragWindow<'a, Message> {
+#[allow(missing_debug_implementations)]
+pub struct DragWindow<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
     width: Length,
     height: Length,
     message: Message,
     title: Option<&'a str>,
     title_color: Option<Color>,
+    title_alignment: TitleAlignment,
+    title_font: Renderer::Font,
+    title_size: Option<f32>,
+    subtitle: Option<&'a str>,
+    modified: bool,
+    on_click: Option<Message>,
+    on_double_click: Option<Message>,
+    double_click_interval: Duration,
+    double_click_distance: f32,
+    drag_threshold: f32,
+    content: Option<Element<'a, Message, Renderer>>,
 }
 
-impl<'a, Message> DragWindow<'a, Message> {
+impl<'a, Message, Renderer> DragWindow<'a, Message, Renderer>
+where
+    Renderer: iced_native::text::Renderer,
+{
     /// Creates an amount of empty [`Space`] with the given width and height.
     pub fn new(width: impl Into<Length>, height: impl Into<Length>, message: Message) -> Self {
         DragWindow {
@@ -24,29 +65,45 @@ impl<'a, Message> DragWindow<'a, Message> {
             message,
             title: None,
             title_color: None,
+            title_alignment: TitleAlignment::default(),
+            title_font: Renderer::Font::default(),
+            title_size: None,
+            subtitle: None,
+            modified: false,
+            on_click: None,
+            on_double_click: None,
+            double_click_interval: Duration::from_millis(500),
+            double_click_distance: 4.0,
+            drag_threshold: 4.0,
+            content: None,
         }
     }
 
     /// Creates an amount of horizontal [`Space`].
     pub fn with_width(width: impl Into<Length>, message: Message) -> Self {
+        Self::new(width, Length::Fill, message)
+    }
+
+    /// Creates a drag area around some content, like tabs or a search box
+    ///
+    /// Presses the content doesn't capture start a drag
+    pub fn with_content(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        message: Message,
+    ) -> Self {
+        let content = content.into();
+
         DragWindow {
-            width: width.into(),
-            height: Length::Fill,
-            message,
-            title: None,
-            title_color: None,
+            width: content.as_widget().width(),
+            height: content.as_widget().height(),
+            content: Some(content),
+            ..Self::new(Length::Shrink, Length::Shrink, message)
         }
     }
 
     /// Creates an amount of vertical [`Space`].
     pub fn with_height(height: impl Into<Length>, message: Message) -> Self {
-        DragWindow {
-            width: Length::Fill,
-            height: height.into(),
-            message,
-            title: None,
-            title_color: None,
-        }
+        Self::new(Length::Fill, height, message)
     }
 
     pub fn set_title(mut self, title: Option<&'a str>) -> Self {
@@ -58,9 +115,71 @@ impl<'a, Message> DragWindow<'a, Message> {
         self.title_color = title_color;
         self
     }
+
+    /// Sets where the title is placed
+    pub fn title_alignment(mut self, alignment: TitleAlignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    /// Sets the font of the title
+    pub fn title_font(mut self, font: Renderer::Font) -> Self {
+        self.title_font = font;
+        self
+    }
+
+    /// Sets the text size of the title, the default size of the renderer is used otherwise
+    pub fn title_size(mut self, size: f32) -> Self {
+        self.title_size = Some(size);
+        self
+    }
+
+    /// Sets a dimmer text shown after the title, like the path of the open file
+    pub fn set_subtitle(mut self, subtitle: Option<&'a str>) -> Self {
+        self.subtitle = subtitle;
+        self
+    }
+
+    /// Sets whether a dot after the title marks unsaved changes
+    pub fn modified(mut self, modified: bool) -> Self {
+        self.modified = modified;
+        self
+    }
+
+    /// Sets the message produced by a press that is released before it turns into a drag
+    pub fn on_click(mut self, message: Message) -> Self {
+        self.on_click = Some(message);
+        self
+    }
+
+    /// Sets the message produced by a double click, instead of starting a drag
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    /// Sets the longest time between two presses that still counts as a double click
+    pub fn double_click_interval(mut self, interval: Duration) -> Self {
+        self.double_click_interval = interval;
+        self
+    }
+
+    /// Sets how far apart two presses can be and still count as a double click
+    pub fn double_click_distance(mut self, distance: f32) -> Self {
+        self.double_click_distance = distance;
+        self
+    }
+
+    /// Sets how far the cursor has to move while pressed to start a drag
+    ///
+    /// A threshold of zero starts the drag right away on the press
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = threshold;
+        self
+    }
 }
 
-impl<'a, Message, Renderer> Widget<Message, Renderer> for DragWindow<'a, Message>
+impl<'a, Message, Renderer> Widget<Message, Renderer> for DragWindow<'a, Message, Renderer>
 where
     Renderer: iced_native::Renderer + iced_native::text::Renderer,
     Message: Clone,
@@ -73,29 +192,128 @@ where
         self.height
     }
 
-    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<DragWindowState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(DragWindowState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match &self.content {
+            Some(content) => tree.diff_children(std::slice::from_ref(content)),
+            None => tree.children.clear(),
+        }
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
 
-        layout::Node::new(limits.resolve(Size::ZERO))
+        match &self.content {
+            Some(content) => {
+                let child = content.as_widget().layout(renderer, &limits);
+                layout::Node::with_children(limits.resolve(child.size()), vec![child])
+            }
+            None => layout::Node::new(limits.resolve(Size::ZERO)),
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let (Some(content), Some(child)) = (&self.content, layout.children().next()) {
+            content
+                .as_widget()
+                .operate(&mut tree.children[0], child, renderer, operation);
+        }
     }
 
     fn on_event(
         &mut self,
-        _state: &mut iced_native::widget::Tree,
+        tree: &mut iced_native::widget::Tree,
         event: iced_native::Event,
         layout: iced_native::Layout<'_>,
         cursor_position: iced_native::Point,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn iced_native::Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced_native::Clipboard,
         shell: &mut iced_native::Shell<'_, Message>,
     ) -> iced_native::event::Status {
+        // interactive content keeps the presses it captures
+        if let (Some(content), Some(child)) = (&mut self.content, layout.children().next()) {
+            let status = content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                child,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let state = tree.state.downcast_mut::<DragWindowState>();
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if layout.bounds().contains(cursor_position) {
-                    shell.publish(self.message.clone())
+                    let now = Instant::now();
+
+                    let double_click = state.last_press.map_or(false, |(time, position)| {
+                        now.duration_since(time) <= self.double_click_interval
+                            && position.distance(cursor_position) <= self.double_click_distance
+                    });
+
+                    match &self.on_double_click {
+                        Some(on_double_click) if double_click => {
+                            state.last_press = None;
+                            state.pressed_at = None;
+                            shell.publish(on_double_click.clone());
+                        }
+                        _ if self.drag_threshold <= 0.0 => {
+                            state.last_press = Some((now, cursor_position));
+                            shell.publish(self.message.clone());
+                        }
+                        _ => {
+                            state.last_press = Some((now, cursor_position));
+                            state.pressed_at = Some(cursor_position);
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                // the window manager takes over the cursor once the drag starts
+                if let Some(pressed_at) = state.pressed_at {
+                    if pressed_at.distance(position) > self.drag_threshold {
+                        state.pressed_at = None;
+                        shell.publish(self.message.clone());
+                    }
                 }
             }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if state.pressed_at.take().is_some() {
+                    if let Some(on_click) = &self.on_click {
+                        shell.publish(on_click.clone());
+                    }
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_at = None;
+            }
             _ => {}
         }
 
@@ -104,55 +322,210 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
-        _theme: &Renderer::Theme,
+        theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
-        _viewport: &Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
     ) {
-        if let Some(title) = &self.title {
-            let bounds = layout.bounds();
+        self.draw_title(renderer, style, layout, viewport);
 
-            let horizontal_alignment = alignment::Horizontal::Center;
-            let vertical_alignment = alignment::Vertical::Center;
+        if let (Some(content), Some(child)) = (&self.content, layout.children().next()) {
+            content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                child,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
 
-            let x = match horizontal_alignment {
-                alignment::Horizontal::Left => bounds.x,
-                alignment::Horizontal::Center => bounds.center_x(),
-                alignment::Horizontal::Right => bounds.x + bounds.width,
-            };
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match (&self.content, layout.children().next()) {
+            (Some(content), Some(child)) => content.as_widget().mouse_interaction(
+                &tree.children[0],
+                child,
+                cursor_position,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
 
-            let y = match vertical_alignment {
-                alignment::Vertical::Top => bounds.y,
-                alignment::Vertical::Center => bounds.center_y(),
-                alignment::Vertical::Bottom => bounds.y + bounds.height,
-            };
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let content = self.content.as_mut()?;
+        let child = layout.children().next()?;
 
+        content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], child, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> DragWindow<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+{
+    /// Draws the title with its subtitle and modified dot
+    fn draw_title(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        viewport: &Rectangle,
+    ) {
+        let Some(title) = self.title else { return };
+
+        let bounds = layout.bounds();
+        let size = self.title_size.unwrap_or_else(|| renderer.default_size());
+        let subtitle_size = size * 0.85;
+        let gap = size * 0.5;
+        let padding = size * 0.5;
+        let dot = size * 0.4;
+
+        let color = self.title_color.unwrap_or(style.text_color);
+        let subtitle_color = Color {
+            a: color.a * 0.6,
+            ..color
+        };
+
+        // the title is kept over the subtitle when the space runs out
+        let available = (bounds.width - 2.0 * padding).max(0.0);
+        let dot_width = if self.modified { gap + dot } else { 0.0 };
+        let title = truncate(
+            renderer,
+            title,
+            size,
+            self.title_font,
+            available - dot_width,
+        );
+        let title_width = measure(renderer, &title, size, self.title_font);
+
+        let remaining = available - title_width - dot_width - gap;
+        let subtitle = self
+            .subtitle
+            .map(|subtitle| {
+                truncate(
+                    renderer,
+                    subtitle,
+                    subtitle_size,
+                    self.title_font,
+                    remaining,
+                )
+            })
+            .filter(|subtitle| !subtitle.is_empty());
+        let subtitle_width = subtitle.as_ref().map_or(0.0, |subtitle| {
+            gap + measure(renderer, subtitle, subtitle_size, self.title_font)
+        });
+
+        let width = title_width + dot_width + subtitle_width;
+        let start = bounds.x + padding;
+        let end = bounds.x + bounds.width - padding - width;
+        let mut x = match self.title_alignment {
+            TitleAlignment::Left => start,
+            TitleAlignment::CenterWindow => (viewport.center_x() - width / 2.0).min(end).max(start),
+            TitleAlignment::CenterRemaining => bounds.center_x() - width / 2.0,
+        };
+        let y = bounds.center_y();
+
+        let fill = |renderer: &mut Renderer, content: &str, x: f32, size: f32, color: Color| {
             renderer.fill_text(Text {
-                content: title,
+                content,
                 bounds: Rectangle { x, y, ..bounds },
-                size: renderer.default_size(),
-                color: if let Some(color) = self.title_color {
-                    color
-                } else {
-                    style.text_color
+                size,
+                color,
+                font: self.title_font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        };
+
+        fill(renderer, &title, x, size, color);
+        x += title_width;
+
+        if self.modified {
+            x += gap;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: y - dot / 2.0,
+                        width: dot,
+                        height: dot,
+                    },
+                    border_radius: (dot / 2.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
                 },
-                font: Default::default(),
-                horizontal_alignment,
-                vertical_alignment,
-            })
+                color,
+            );
+            x += dot;
+        }
+
+        if let Some(subtitle) = subtitle {
+            fill(renderer, &subtitle, x + gap, subtitle_size, subtitle_color);
         }
     }
 }
 
-impl<'a, Message, Renderer> From<DragWindow<'a, Message>> for Element<'a, Message, Renderer>
+/// Measures the width of a single line of text
+fn measure<Renderer>(renderer: &Renderer, content: &str, size: f32, font: Renderer::Font) -> f32
 where
-    Renderer: iced_native::Renderer + iced_native::text::Renderer,
+    Renderer: iced_native::text::Renderer,
+{
+    renderer.measure(content, size, font, Size::INFINITY).0
+}
+
+/// Shortens the text with an ellipsis until it fits into `max_width`
+///
+/// The text is dropped completely when not even the ellipsis fits
+fn truncate<'b, Renderer>(
+    renderer: &Renderer,
+    content: &'b str,
+    size: f32,
+    font: Renderer::Font,
+    max_width: f32,
+) -> Cow<'b, str>
+where
+    Renderer: iced_native::text::Renderer,
+{
+    if measure(renderer, content, size, font) <= max_width {
+        return Cow::Borrowed(content);
+    }
+
+    content
+        .char_indices()
+        .rev()
+        .map(|(i, _)| format!("{}…", content[..i].trim_end()))
+        .find(|shortened| measure(renderer, shortened, size, font) <= max_width)
+        .map_or(Cow::Borrowed(""), Cow::Owned)
+}
+
+impl<'a, Message, Renderer> From<DragWindow<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer + iced_native::text::Renderer + 'a,
     Message: 'a + Clone,
 {
-    fn from(space: DragWindow<'a, Message>) -> Element<'a, Message, Renderer> {
+    fn from(space: DragWindow<'a, Message, Renderer>) -> Element<'a, Message, Renderer> {
         Element::new(space)
     }
 }
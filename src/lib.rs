@@ -1,3 +1,4 @@
+pub mod accessibility;
 pub mod menu;
 pub mod resize;
 pub mod theme_handler;
@@ -61,4 +61,6 @@ mod style;
 pub use style::{Appearance, StyleSheet};
 pub use menu_bar::MenuBar;
 pub use menu_inner::{CloseCondition, ItemHeight, ItemWidth, PathHighlight};
-pub use menu_tree::MenuTree;
\ No newline at end of file
+pub use menu_tree::MenuTree;
+
+pub(crate) use menu_bar::MenuBarState;
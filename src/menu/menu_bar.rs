@@ -1,17 +1,20 @@
 //! A widget that handles menu trees
 
 use super::menu_inner::{
-    CloseCondition, Direction, ItemHeight, ItemWidth, Menu, MenuState, PathHighlight,
+    load_menu_path, remap_menu_states, sync_menu_states, CloseCondition, Direction, ItemHeight,
+    ItemWidth, Menu, MenuState, PathHighlight, TypeAhead,
 };
-use super::menu_tree::MenuTree;
+use super::menu_tree::{MenuTree, Step};
 use super::style::StyleSheet;
-use iced_native::widget::{tree, Tree};
+use iced_native::widget::{tree, Id, Operation, Tree};
 use iced_native::{
-    event, layout, mouse, overlay, renderer, touch, Alignment, Clipboard, Color, Element, Length,
-    Padding, Point, Rectangle, Shell, Widget,
+    event, layout, mouse, overlay, renderer, text, touch, window, Alignment, Clipboard, Color,
+    Element, Length, Padding, Point, Rectangle, Shell, Size, Vector, Widget,
 };
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
-pub(super) struct MenuBarState {
+pub(crate) struct MenuBarState {
     pub(super) pressed: bool,
     pub(super) view_cursor: Point,
     pub(super) open: bool,
@@ -19,6 +22,25 @@ pub(super) struct MenuBarState {
     pub(super) horizontal_direction: Direction,
     pub(super) vertical_direction: Direction,
     pub(super) menu_states: Vec<MenuState>,
+    pub(super) type_ahead: TypeAhead,
+    pub(super) hovered_path: Option<Vec<usize>>,
+    /// The tooltip last handed to the hover callback
+    pub(super) hovered_tooltip: Option<String>,
+    pub(super) hover_start: Instant,
+    pub(super) viewport_size: Size,
+    pub(super) overlay_offset: Vector,
+    /// The keys of the roots, as of the last diff
+    pub(super) root_keys: Vec<Option<u64>>,
+    /// The ids of the flattened menu trees of each root, as of the last diff
+    pub(super) flat_ids: Vec<Vec<Vec<Step>>>,
+    /// The widget trees of each root the last diff had no items for,
+    /// lazy menus take them back when they're loaded
+    pub(super) stashed_items: Vec<HashMap<Vec<Step>, Tree>>,
+    /// Changes whenever the menu trees may have changed,
+    /// the bounds of the open menus are rebuilt when it does
+    pub(super) generation: u64,
+    /// The keys along the open path, starting with the active root
+    pub(super) open_keys: Vec<Option<u64>>,
 }
 impl MenuBarState {
     pub(super) fn get_trimmed_indices(&self) -> impl Iterator<Item = usize> + '_ {
@@ -28,10 +50,61 @@ impl MenuBarState {
             .map(|ms| ms.index.expect("No indices were found in the menu state."))
     }
 
+    /// The path of the open menus as indices, starting with the active root
+    pub(crate) fn open_path(&self) -> Option<Vec<usize>> {
+        let active_root = self.active_root.filter(|_| self.open)?;
+
+        Some(
+            std::iter::once(active_root)
+                .chain(self.get_trimmed_indices())
+                .collect(),
+        )
+    }
+
+    /// Remembers the keys along the open path, so the path can be followed
+    /// when the menu trees are rebuilt
+    pub(super) fn update_open_keys<Message, Renderer>(
+        &mut self,
+        menu_roots: &[MenuTree<'_, Message, Renderer>],
+    ) {
+        self.open_keys.clear();
+
+        let Some(root) = self.active_root.and_then(|i| menu_roots.get(i)) else { return; };
+        self.open_keys.push(root.key);
+
+        let mut menu_tree = root;
+        for index in self.get_trimmed_indices() {
+            let Some(child) = menu_tree.children.get(index) else { break; };
+            menu_tree = child;
+            self.open_keys.push(menu_tree.key);
+        }
+    }
+
     pub(super) fn reset(&mut self) {
         self.open = false;
         self.active_root = None;
         self.menu_states.clear();
+        self.type_ahead.clear();
+    }
+}
+#[cfg(test)]
+impl MenuBarState {
+    /// A state with the menus along `path` open, the first index is the active root
+    pub(crate) fn opened(path: &[usize]) -> Self {
+        let (&active_root, indices) = path.split_first().expect("empty open path");
+        let mut menu_states = indices
+            .iter()
+            .map(|&index| MenuState::with_index(Some(index)))
+            .collect::<Vec<_>>();
+        // the last menu is open without an active item
+        menu_states.push(MenuState::with_index(None));
+
+        Self {
+            open: true,
+            active_root: Some(active_root),
+            menu_states,
+            ..Self::default()
+        }
     }
 }
 impl Default for MenuBarState {
@@ -44,6 +117,17 @@ impl Default for MenuBarState {
             horizontal_direction: Direction::Positive,
             vertical_direction: Direction::Positive,
             menu_states: Vec::new(),
+            type_ahead: TypeAhead::default(),
+            hovered_path: None,
+            hovered_tooltip: None,
+            hover_start: Instant::now(),
+            viewport_size: Size::ZERO,
+            overlay_offset: Vector::new(0.0, 0.0),
+            root_keys: Vec::new(),
+            flat_ids: Vec::new(),
+            stashed_items: Vec::new(),
+            generation: 0,
+            open_keys: Vec::new(),
         }
     }
 }
@@ -65,6 +149,9 @@ where
     item_width: ItemWidth,
     item_height: ItemHeight,
     path_highlight: Option<PathHighlight>,
+    tooltip_delay: Duration,
+    on_hover: Option<Box<dyn Fn(Option<String>) -> Message + 'a>>,
+    id: Option<Id>,
     menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
@@ -93,6 +180,9 @@ where
             item_width: ItemWidth::Uniform(150),
             item_height: ItemHeight::Uniform(30),
             path_highlight: Some(PathHighlight::MenuActive),
+            tooltip_delay: Duration::from_millis(500),
+            on_hover: None,
+            id: None,
             menu_roots,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
@@ -165,6 +255,45 @@ where
         self
     }
 
+    /// Sets how long an item has to be hovered before its tooltip is shown
+    #[must_use]
+    pub fn tooltip_delay(mut self, tooltip_delay: Duration) -> Self {
+        self.tooltip_delay = tooltip_delay;
+        self
+    }
+
+    /// Sets the message that is produced when the hovered item changes
+    ///
+    /// The message carries the tooltip of the hovered item,
+    /// or `None` once the cursor leaves the items with tooltips.
+    /// Moving between items without tooltips produces nothing
+    #[must_use]
+    pub fn on_hover<F>(mut self, on_hover: F) -> Self
+    where
+        F: 'a + Fn(Option<String>) -> Message,
+    {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+
+    /// Sets the [`Id`] the open menus of the [`MenuBar`] are tagged with,
+    /// see [`open_menus`](crate::accessibility::open_menus)
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The [`Id`] of the [`MenuBar`], if it has one
+    pub(crate) fn widget_id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
+
+    /// The menu roots of the [`MenuBar`]
+    pub(crate) fn menu_roots(&self) -> &[MenuTree<'a, Message, Renderer>] {
+        &self.menu_roots
+    }
+
     /// Sets the style of the menu bar and its menus
     #[must_use]
     pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
@@ -174,7 +303,7 @@ where
 }
 impl<'a, Message, Renderer> Widget<Message, Renderer> for MenuBar<'a, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn width(&self) -> Length {
@@ -186,44 +315,101 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
-        if tree.children.len() > self.menu_roots.len() {
-            tree.children.truncate(self.menu_roots.len());
-        }
+        /*
+        Keyed menu trees are matched by key, the rest by position,
+        a keyed menu tree never takes over the state of an unkeyed one
+        */
 
-        tree.children
-            .iter_mut()
-            .zip(self.menu_roots.iter())
-            .for_each(|(t, root)| {
-                let flat = root
-                    .flattern()
-                    .iter()
-                    .map(|mt| mt.item.as_widget())
-                    .collect::<Vec<_>>();
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        let old_root_keys = std::mem::take(&mut state.root_keys);
+        let mut old_ids = std::mem::take(&mut state.flat_ids);
 
-                t.diff_children(&flat);
-            });
+        let mut old_roots = std::mem::take(&mut tree.children)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
 
-        if tree.children.len() < self.menu_roots.len() {
-            let extended = self.menu_roots[tree.children.len()..].iter().map(|root| {
-                let mut tree = Tree::empty();
-                let flat = root
-                    .flattern()
-                    .iter()
-                    .map(|mt| Tree::new(mt.item.as_widget()))
-                    .collect();
-                tree.children = flat;
-                tree
-            });
-            tree.children.extend(extended);
-        }
+        let mut stashed_items = Vec::with_capacity(self.menu_roots.len());
+        let (children, flat_ids): (Vec<_>, Vec<_>) = self
+            .menu_roots
+            .iter()
+            .enumerate()
+            .map(|(i, root)| {
+                let flat = root.flattern();
+                let ids = root.flat_ids();
+
+                let tree = match take_matching(root.key, i, &old_root_keys, &mut old_roots) {
+                    Some((old_i, mut t)) => {
+                        // items are matched by the steps from the root, so keys only
+                        // have to be unique among siblings
+                        let item_ids = old_ids.get_mut(old_i).map(std::mem::take);
+                        let mut old_items = item_ids
+                            .unwrap_or_default()
+                            .into_iter()
+                            .zip(std::mem::take(&mut t.children))
+                            .collect::<HashMap<_, _>>();
+
+                        t.children = flat
+                            .iter()
+                            .zip(&ids)
+                            .map(|(mt, id)| match old_items.remove(id) {
+                                Some(mut t) => {
+                                    t.diff(mt.item.as_widget());
+                                    t
+                                }
+                                None => Tree::new(mt.item.as_widget()),
+                            })
+                            .collect();
+                        stashed_items.push(old_items);
+                        t
+                    }
+                    None => {
+                        stashed_items.push(HashMap::new());
+                        let mut t = Tree::empty();
+                        t.children = flat.iter().map(|mt| Tree::new(mt.item.as_widget())).collect();
+                        t
+                    }
+                };
+
+                (tree, ids)
+            })
+            .unzip();
+
+        tree.children = children;
+
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        state.root_keys = self.menu_roots.iter().map(|root| root.key).collect();
+        state.flat_ids = flat_ids;
+        state.stashed_items = stashed_items;
+        state.generation = state.generation.wrapping_add(1);
+
+        // follow the open path to where its items are now
+        remap_menu_states(state, &self.menu_roots);
     }
 
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<MenuBarState>()
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: layout::Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        operation.custom(state, self.id.as_ref());
+    }
+
     fn state(&self) -> tree::State {
-        tree::State::new(MenuBarState::default())
+        // the first diff matches the items against these
+        tree::State::new(MenuBarState {
+            root_keys: self.menu_roots.iter().map(|root| root.key).collect(),
+            flat_ids: self.menu_roots.iter().map(MenuTree::flat_ids).collect(),
+            stashed_items: self.menu_roots.iter().map(|_| HashMap::new()).collect(),
+            ..MenuBarState::default()
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -306,6 +492,37 @@ where
             }
             _ => (),
         }
+
+        // track the hovered item, the overlay has already updated the open path
+        let hovered_path = state.open_path().filter(|path| path.len() > 1);
+        if hovered_path != state.hovered_path {
+            state.hover_start = Instant::now();
+
+            let tooltip = hovered_path.as_ref().and_then(|path| {
+                path[1..]
+                    .iter()
+                    .try_fold(self.menu_roots.get(path[0])?, |mt, &i| mt.children.get(i))?
+                    .tooltip
+                    .clone()
+            });
+            // the application only hears about changes of what it can show
+            if tooltip != state.hovered_tooltip {
+                if let Some(on_hover) = &self.on_hover {
+                    shell.publish(on_hover(tooltip.clone()));
+                }
+                state.hovered_tooltip = tooltip;
+            }
+
+            if hovered_path.is_some() {
+                shell.request_redraw(window::RedrawRequest::At(
+                    state.hover_start + self.tooltip_delay,
+                ));
+            }
+            state.hovered_path = hovered_path;
+        }
+
+        state.update_open_keys(&self.menu_roots);
+
         root_status
     }
 
@@ -370,11 +587,34 @@ where
         layout: layout::Layout<'_>,
         _renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        let state = tree.state.downcast_ref::<MenuBarState>();
+        let state = tree.state.downcast_mut::<MenuBarState>();
         if !state.open {
             return None;
         }
 
+        // the view dropped the children of the lazy menus along the open path
+        loop {
+            let state = tree.state.downcast_mut::<MenuBarState>();
+            let Some(path) = state.open_path() else { break; };
+            if !load_menu_path(tree, &mut self.menu_roots, &path) {
+                break;
+            }
+
+            let state = tree.state.downcast_mut::<MenuBarState>();
+            remap_menu_states(state, &self.menu_roots);
+        }
+
+        // the menu trees may have changed since the menus were opened
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        sync_menu_states(
+            state,
+            &self.menu_roots,
+            self.item_width,
+            self.item_height,
+            self.bounds_expand,
+        );
+        state.update_open_keys(&self.menu_roots);
+
         Some(
             Menu {
                 tree,
@@ -386,6 +626,7 @@ where
                 bar_bounds: layout.bounds(),
                 root_bounds_list: layout.children().map(|lo| lo.bounds()).collect(),
                 path_highlight: self.path_highlight,
+                tooltip_delay: self.tooltip_delay,
                 style: &self.style,
             }
             .overlay(),
@@ -395,7 +636,7 @@ where
 impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Message: 'a,
-    Renderer: 'a + renderer::Renderer,
+    Renderer: 'a + renderer::Renderer + text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn from(value: MenuBar<'a, Message, Renderer>) -> Self {
@@ -435,3 +676,107 @@ where
         })
         .fold(event::Status::Ignored, event::Status::merge)
 }
+
+/// Takes the old root matching a new one,
+/// keyed roots are matched by key, unkeyed roots by position
+fn take_matching<T>(
+    key: Option<u64>,
+    position: usize,
+    old_keys: &[Option<u64>],
+    old: &mut [Option<T>],
+) -> Option<(usize, T)> {
+    let index = match key {
+        Some(_) => old_keys.iter().position(|k| *k == key)?,
+        None => position,
+    };
+
+    // unknown old keys are treated as unkeyed
+    if old_keys.get(index).copied().flatten() != key {
+        return None;
+    }
+
+    old.get_mut(index)?.take().map(|t| (index, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{renderer::Null, widget::Space};
+
+    use super::*;
+
+    type Item = MenuTree<'static, (), Null>;
+
+    fn item(key: &str) -> Item {
+        MenuTree::new(Space::new(Length::Shrink, Length::Shrink)).key(key)
+    }
+
+    fn menu(key: &str, children: Vec<Item>) -> Item {
+        MenuTree::with_children(Space::new(Length::Shrink, Length::Shrink), children).key(key)
+    }
+
+    /// Opens `path` in a menu bar built from `old`, then rebuilds the menu bar from `new`
+    fn rebuild(old: Vec<Item>, path: &[usize], new: Vec<Item>) -> Tree {
+        let old = MenuBar::new(old);
+        let mut tree = Tree::new(&old as &dyn Widget<(), Null>);
+
+        let opened = MenuBarState::opened(path);
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        state.open = opened.open;
+        state.active_root = opened.active_root;
+        state.menu_states = opened.menu_states;
+        state.update_open_keys(&old.menu_roots);
+
+        MenuBar::new(new).diff(&mut tree);
+        tree
+    }
+
+    fn open_path(tree: &Tree) -> Option<Vec<usize>> {
+        tree.state.downcast_ref::<MenuBarState>().open_path()
+    }
+
+    #[test]
+    fn open_item_follows_an_inserted_sibling() {
+        let tree = rebuild(
+            vec![menu("file", vec![item("a"), menu("b", vec![item("x")])])],
+            &[0, 1],
+            vec![menu(
+                "file",
+                vec![item("new"), item("a"), menu("b", vec![item("x")])],
+            )],
+        );
+
+        assert_eq!(open_path(&tree), Some(vec![0, 2]));
+        // the root, its three items and the submenu item
+        assert_eq!(tree.children[0].children.len(), 5);
+    }
+
+    #[test]
+    fn open_path_is_cut_at_a_removed_item() {
+        let tree = rebuild(
+            vec![menu(
+                "file",
+                vec![item("a"), menu("b", vec![item("x"), item("y")])],
+            )],
+            &[0, 1, 0],
+            vec![menu("file", vec![item("a")])],
+        );
+
+        let state = tree.state.downcast_ref::<MenuBarState>();
+        assert_eq!(state.menu_states.len(), 1);
+        assert_eq!(state.menu_states[0].index, None);
+        assert_eq!(open_path(&tree), Some(vec![0]));
+    }
+
+    #[test]
+    fn open_root_follows_its_key() {
+        let file = || menu("file", vec![item("open")]);
+        let edit = || menu("edit", vec![item("cut"), item("copy")]);
+
+        let tree = rebuild(vec![file(), edit()], &[1, 1], vec![edit(), file()]);
+
+        assert_eq!(open_path(&tree), Some(vec![0, 1]));
+        let state = tree.state.downcast_ref::<MenuBarState>();
+        assert_eq!(state.root_keys, vec![edit().key, file().key]);
+        assert_eq!(tree.children[0].children.len(), 3);
+    }
+}
@@ -2,12 +2,13 @@
 
 use super::menu_bar::MenuBarState;
 use super::menu_tree::MenuTree;
-use super::style::StyleSheet;
+use super::style::{Appearance, StyleSheet};
 use iced_native::widget::Tree;
 use iced_native::{
-    event, layout, mouse, overlay, renderer, touch, Clipboard, Color, Padding, Point, Rectangle,
-    Shell, Size, Vector,
+    alignment, event, keyboard, layout, mouse, overlay, renderer, text, touch, Clipboard, Color,
+    Padding, Point, Rectangle, Shell, Size, Vector,
 };
+use std::time::{Duration, Instant};
 
 /// The condition of when to close a menu
 #[derive(Debug, Clone, Copy)]
@@ -237,12 +238,73 @@ impl MenuBounds {
     }
 }
 
+/// The prefix typed while a menu is open
+///
+/// Characters typed in quick succession are accumulated,
+/// the prefix is cleared after a short pause
+#[derive(Debug, Default)]
+pub(super) struct TypeAhead {
+    buffer: String,
+    last_input: Option<Instant>,
+}
+impl TypeAhead {
+    /// The pause after which the prefix starts over
+    const TIMEOUT: Duration = Duration::from_millis(1000);
+
+    fn push(&mut self, c: char) {
+        let now = Instant::now();
+        let expired = self
+            .last_input
+            .map_or(true, |last| now.duration_since(last) > Self::TIMEOUT);
+
+        if expired {
+            self.buffer.clear();
+        }
+        self.buffer.extend(c.to_lowercase());
+        self.last_input = Some(now);
+    }
+
+    fn matches(&self, label: &str) -> bool {
+        label.to_lowercase().starts_with(&self.buffer)
+    }
+
+    /// Whether the active item was last chosen by typing
+    pub(super) fn is_active(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.buffer.clear();
+        self.last_input = None;
+    }
+}
+
 pub(super) struct MenuState {
     pub(super) index: Option<usize>,
     scroll_offset: f32,
     menu_bounds: MenuBounds,
+    /// The generation of the menu trees the bounds were built for
+    generation: u64,
 }
 impl MenuState {
+    /// A menu state with empty bounds, for tests
+    #[cfg(test)]
+    pub(super) fn with_index(index: Option<usize>) -> Self {
+        let empty = Rectangle::new(Point::ORIGIN, Size::ZERO);
+
+        Self {
+            index,
+            scroll_offset: 0.0,
+            menu_bounds: MenuBounds {
+                child_positions: Vec::new(),
+                children_bounds: empty,
+                parent_bounds: empty,
+                check_bounds: empty,
+            },
+            generation: 0,
+        }
+    }
+
     fn layout<Message, Renderer>(
         &self,
         overlay_offset: Vector,
@@ -395,11 +457,12 @@ where
     pub(super) bar_bounds: Rectangle,
     pub(super) root_bounds_list: Vec<Rectangle>,
     pub(super) path_highlight: Option<PathHighlight>,
+    pub(super) tooltip_delay: Duration,
     pub(super) style: &'b <Renderer::Theme as StyleSheet>::Style,
 }
 impl<'a, 'b, Message, Renderer> Menu<'a, 'b, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     pub(super) fn overlay(self) -> overlay::Element<'b, Message, Renderer> {
@@ -409,7 +472,7 @@ where
 impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
     for Menu<'a, 'b, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer,
     Renderer::Theme: StyleSheet,
 {
     fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
@@ -427,9 +490,10 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         use event::{
-            Event::{Mouse, Touch},
+            Event::{Keyboard, Mouse, Touch},
             Status::{Captured, Ignored},
         };
+        use keyboard::Event::CharacterReceived;
         use mouse::{
             Button::Left,
             Event::{ButtonPressed, ButtonReleased, CursorMoved, WheelScrolled},
@@ -445,6 +509,11 @@ where
         let overlay_offset = Point::ORIGIN - viewport.position();
         let overlay_cursor = view_cursor - overlay_offset;
 
+        // kept for updating the menus when the menu trees change
+        let state = self.tree.state.downcast_mut::<MenuBarState>();
+        state.viewport_size = viewport_size;
+        state.overlay_offset = overlay_offset;
+
         let menu_status = process_menu_events(
             self.tree,
             self.menu_roots,
@@ -459,6 +528,7 @@ where
 
         init_root_menu(
             self,
+            shell,
             overlay_cursor,
             viewport_size,
             overlay_offset,
@@ -471,6 +541,10 @@ where
                     .merge(menu_status)
             }
 
+            Keyboard(CharacterReceived(c)) => {
+                process_type_ahead(self, c, viewport_size, overlay_offset).merge(menu_status)
+            }
+
             Mouse(ButtonPressed(Left)) | Touch(FingerPressed { .. }) => {
                 let state = self.tree.state.downcast_mut::<MenuBarState>();
                 state.pressed = true;
@@ -483,6 +557,7 @@ where
                 let overlay_cursor = view_cursor - overlay_offset;
                 process_overlay_events(
                     self,
+                    shell,
                     viewport_size,
                     overlay_offset,
                     view_cursor,
@@ -559,7 +634,7 @@ where
                     PathHighlight::Full => true,
                     PathHighlight::OmitActive => !indices.is_empty() && i < indices.len() - 1,
                     PathHighlight::MenuActive => i < state.menu_states.len() - 1,
-                });
+                }) || (i == state.menu_states.len() - 1 && state.type_ahead.is_active());
 
                 // react only to the last menu
                 let view_cursor = if i == state.menu_states.len() - 1 {
@@ -631,9 +706,95 @@ where
                 ms.index
                     .map_or(menu_root, |active| &menu_root.children[active])
             });
+
+        if state.hovered_path.is_some() && state.hover_start.elapsed() >= self.tooltip_delay {
+            renderer.with_layer(render_bounds, |r| {
+                draw_tooltip(self, r, &styling, viewport_size, overlay_offset);
+            });
+        }
     }
 }
 
+/// Draws the tooltip of the active item next to it
+fn draw_tooltip<Message, Renderer>(
+    menu: &Menu<'_, '_, Message, Renderer>,
+    renderer: &mut Renderer,
+    styling: &Appearance,
+    viewport_size: Size,
+    overlay_offset: Vector,
+) where
+    Renderer: renderer::Renderer + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The space between the tooltip text and its border
+    const PADDING: f32 = 4.0;
+
+    let state = menu.tree.state.downcast_ref::<MenuBarState>();
+    let Some(active_root) = state.active_root else { return; };
+
+    let indices = state.get_trimmed_indices().collect::<Vec<_>>();
+    let Some(&active) = indices.last() else { return; };
+
+    let item = indices
+        .iter()
+        .fold(&menu.menu_roots[active_root], |mt, &i| &mt.children[i]);
+
+    // a submenu takes the place of the tooltip
+    let (Some(tooltip), true) = (&item.tooltip, item.children.is_empty()) else { return; };
+
+    // viewport space item bounds
+    let ms = &state.menu_states[indices.len() - 1];
+    let children_bounds = ms.menu_bounds.children_bounds + overlay_offset;
+    let item_bounds = Rectangle::new(
+        Point::new(
+            children_bounds.x,
+            children_bounds.y + ms.menu_bounds.child_positions[active] + ms.scroll_offset,
+        ),
+        get_item_size(item, children_bounds.width, menu.item_height),
+    );
+
+    let size = renderer.default_size();
+    let (width, height) = renderer.measure(tooltip, size, Default::default(), Size::INFINITY);
+    let tooltip_size = Size::new(width + PADDING * 2.0, height + PADDING * 2.0);
+
+    let aod = Aod {
+        horizontal: true,
+        vertical: true,
+        horizontal_overlap: false,
+        vertical_overlap: true,
+        horizontal_direction: state.horizontal_direction,
+        vertical_direction: state.vertical_direction,
+    };
+    let bounds = Rectangle::new(
+        aod.point(item_bounds, tooltip_size, viewport_size),
+        tooltip_size,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: styling.border_radius.into(),
+            border_width: styling.border_width,
+            border_color: styling.border_color,
+        },
+        styling.tooltip_background,
+    );
+    renderer.fill_text(text::Text {
+        content: tooltip,
+        bounds: Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width,
+            height,
+        },
+        size,
+        color: styling.tooltip_text,
+        font: Default::default(),
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+    });
+}
+
 fn pad_rectangle(rect: Rectangle, padding: Padding) -> Rectangle {
     Rectangle {
         x: rect.x - padding.left,
@@ -645,6 +806,7 @@ fn pad_rectangle(rect: Rectangle, padding: Padding) -> Rectangle {
 
 fn init_root_menu<Message, Renderer>(
     menu: &mut Menu<'_, '_, Message, Renderer>,
+    shell: &mut Shell<'_, Message>,
     overlay_cursor: Point,
     viewport_size: Size,
     overlay_offset: Vector,
@@ -658,56 +820,63 @@ fn init_root_menu<Message, Renderer>(
         return;
     }
 
-    for (i, (&root_bounds, mt)) in menu
+    let hovered = menu
         .root_bounds_list
         .iter()
-        .zip(menu.menu_roots.iter())
-        .enumerate()
-    {
-        if mt.children.is_empty() {
-            continue;
-        }
+        .position(|root_bounds| root_bounds.contains(overlay_cursor));
+    let Some(i) = hovered else { return; };
 
-        if root_bounds.contains(overlay_cursor) {
-            let view_center = viewport_size.width * 0.5;
-            let rb_center = root_bounds.center_x();
+    // a lazy root builds its menu now
+    load_menu_path(menu.tree, menu.menu_roots, &[i]);
 
-            state.horizontal_direction = if rb_center > view_center {
-                Direction::Negative
-            } else {
-                Direction::Positive
-            };
+    let Some(mt) = menu.menu_roots.get(i).filter(|mt| mt.has_menu()) else { return; };
 
-            let aod = Aod {
-                horizontal: true,
-                vertical: true,
-                horizontal_overlap: true,
-                vertical_overlap: false,
-                horizontal_direction: state.horizontal_direction,
-                vertical_direction: state.vertical_direction,
-            };
+    if let Some(on_open) = &mt.on_open {
+        shell.publish(on_open());
+    }
+    if mt.children.is_empty() {
+        return;
+    }
 
-            let menu_bounds = MenuBounds::new(
-                mt,
-                menu.item_width,
-                menu.item_height,
-                viewport_size,
-                overlay_offset,
-                &aod,
-                menu.bounds_expand,
-                root_bounds,
-            );
+    let root_bounds = menu.root_bounds_list[i];
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
 
-            state.active_root = Some(i);
-            state.menu_states.push(MenuState {
-                index: None,
-                scroll_offset: 0.0,
-                menu_bounds,
-            });
+    let view_center = viewport_size.width * 0.5;
+    let rb_center = root_bounds.center_x();
 
-            break;
-        }
-    }
+    state.horizontal_direction = if rb_center > view_center {
+        Direction::Negative
+    } else {
+        Direction::Positive
+    };
+
+    let aod = Aod {
+        horizontal: true,
+        vertical: true,
+        horizontal_overlap: true,
+        vertical_overlap: false,
+        horizontal_direction: state.horizontal_direction,
+        vertical_direction: state.vertical_direction,
+    };
+
+    let menu_bounds = MenuBounds::new(
+        mt,
+        menu.item_width,
+        menu.item_height,
+        viewport_size,
+        overlay_offset,
+        &aod,
+        menu.bounds_expand,
+        root_bounds,
+    );
+
+    state.active_root = Some(i);
+    state.menu_states.push(MenuState {
+        index: None,
+        scroll_offset: 0.0,
+        menu_bounds,
+        generation: state.generation,
+    });
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -770,6 +939,7 @@ where
 #[allow(unused_results)]
 fn process_overlay_events<Message, Renderer>(
     menu: &mut Menu<'_, '_, Message, Renderer>,
+    shell: &mut Shell<'_, Message>,
     viewport_size: Size,
     overlay_offset: Vector,
     view_cursor: Point,
@@ -807,6 +977,9 @@ where
     but I still want Widget::draw() to react to cursor movement */
     state.view_cursor = view_cursor;
 
+    // the cursor takes over the active item from type-ahead
+    state.type_ahead.clear();
+
     // * remove invalid menus
     let mut prev_bounds = std::iter::once(menu.bar_bounds)
         .chain(
@@ -911,24 +1084,36 @@ where
     // set new index
     last_menu_state.index = Some(new_index);
 
+    let item_position = Point::new(
+        0.0,
+        last_menu_bounds.child_positions[new_index] + last_menu_state.scroll_offset,
+    );
+    let path = std::iter::once(active_root)
+        .chain(state.get_trimmed_indices())
+        .collect::<Vec<_>>();
+
+    // a lazy item builds its menu now
+    load_menu_path(menu.tree, menu.menu_roots, &path);
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+
     // get new active item
-    let item = &active_menu.children[new_index];
+    let item = path[1..]
+        .iter()
+        .fold(&menu.menu_roots[active_root], |mt, &i| &mt.children[i]);
+
+    if item.has_menu() {
+        if let Some(on_open) = &item.on_open {
+            shell.publish(on_open());
+        }
+    }
 
     // * add new menu if the new item is a menu
     if !item.children.is_empty() {
-        let item_position = Point::new(
-            0.0,
-            last_menu_bounds.child_positions[new_index] + last_menu_state.scroll_offset,
-        );
-        let item_size = get_item_size(
-            item,
-            last_menu_bounds.children_bounds.width,
-            menu.item_height,
-        );
+        let item_size = get_item_size(item, last_children_bounds.width, menu.item_height);
 
         // overlay space item bounds
         let item_bounds = Rectangle::new(item_position, item_size)
-            + (last_menu_bounds.children_bounds.position() - Point::ORIGIN);
+            + (last_children_bounds.position() - Point::ORIGIN);
 
         let aod = Aod {
             horizontal: true,
@@ -952,7 +1137,253 @@ where
                 menu.bounds_expand,
                 item_bounds,
             ),
+            generation: state.generation,
+        });
+    }
+
+    Captured
+}
+
+/// Builds the children of the lazy menu trees along `path`, the first index is the root,
+/// returns whether any children were built
+///
+/// The widget trees of the root are rebuilt to line up with the new indices,
+/// items that were loaded before the last view get their widget state back
+pub(super) fn load_menu_path<Message, Renderer>(
+    tree: &mut Tree,
+    menu_roots: &mut [MenuTree<'_, Message, Renderer>],
+    path: &[usize],
+) -> bool
+where
+    Renderer: renderer::Renderer,
+{
+    let Some((&root_index, indices)) = path.split_first() else { return false; };
+    let Some(root) = menu_roots.get_mut(root_index) else { return false; };
+
+    let mut loaded = root.load();
+    let mut menu_tree = root;
+    for &index in indices {
+        let Some(child) = menu_tree.children.get_mut(index) else { break; };
+        loaded |= child.load();
+        menu_tree = child;
+    }
+    if !loaded {
+        return false;
+    }
+
+    let root = &mut menu_roots[root_index];
+    root.set_index();
+    let ids = root.flat_ids();
+
+    let state = tree.state.downcast_mut::<MenuBarState>();
+    state.generation = state.generation.wrapping_add(1);
+
+    let (Some(old_ids), Some(stash), Some(root_tree)) = (
+        state.flat_ids.get_mut(root_index),
+        state.stashed_items.get_mut(root_index),
+        tree.children.get_mut(root_index),
+    ) else { return true; };
+
+    let old_ids = std::mem::replace(old_ids, ids.clone());
+    let old_items = std::mem::take(&mut root_tree.children);
+    stash.extend(old_ids.into_iter().zip(old_items));
+
+    root_tree.children = root
+        .flattern()
+        .iter()
+        .zip(&ids)
+        .map(|(mt, id)| match stash.remove(id) {
+            Some(mut t) => {
+                t.diff(mt.item.as_widget());
+                t
+            }
+            None => Tree::new(mt.item.as_widget()),
+        })
+        .collect();
+
+    true
+}
+
+/// Moves the active indices of the open menus to where their keyed items
+/// are after the menu trees were rebuilt
+///
+/// Unkeyed items keep their position, the open path is cut
+/// where a keyed item can no longer be found.
+/// The remapping pauses at a lazy menu that isn't loaded yet,
+/// it's picked up again by calling this once the menu is loaded
+pub(super) fn remap_menu_states<Message, Renderer>(
+    state: &mut MenuBarState,
+    menu_roots: &[MenuTree<'_, Message, Renderer>],
+) {
+    /// finds the new position of an item
+    fn find<Message, Renderer>(
+        menu_trees: &[MenuTree<'_, Message, Renderer>],
+        key: Option<u64>,
+        index: usize,
+    ) -> Option<usize> {
+        match key {
+            Some(_) => menu_trees.iter().position(|mt| mt.key == key),
+            None => Some(index).filter(|&i| i < menu_trees.len()),
+        }
+    }
+
+    let Some(active_root) = state.active_root else { return; };
+    let keys = std::mem::take(&mut state.open_keys);
+
+    let Some(root_index) = find(menu_roots, keys.first().copied().flatten(), active_root) else {
+        state.reset();
+        return;
+    };
+    state.active_root = Some(root_index);
+
+    let mut menu_tree = &menu_roots[root_index];
+    for i in 0..state.menu_states.len() {
+        let Some(index) = state.menu_states[i].index else { break; };
+
+        // the items of a lazy menu are only known once it's loaded again
+        if menu_tree.children.is_empty() && menu_tree.loader.is_some() {
+            state.open_keys = keys;
+            return;
+        }
+
+        let key = keys.get(i + 1).copied().flatten();
+
+        let Some(new_index) = find(&menu_tree.children, key, index) else {
+            state.menu_states[i].index = None;
+            state.menu_states.truncate(i + 1);
+            break;
+        };
+        state.menu_states[i].index = Some(new_index);
+        menu_tree = &menu_tree.children[new_index];
+    }
+}
+
+/// Updates the open menus to match menu trees that changed while they're open,
+/// the bounds are rebuilt once per view and whenever a lazy menu is loaded
+pub(super) fn sync_menu_states<Message, Renderer>(
+    state: &mut MenuBarState,
+    menu_roots: &[MenuTree<'_, Message, Renderer>],
+    item_width: ItemWidth,
+    item_height: ItemHeight,
+    bounds_expand: u16,
+) where
+    Renderer: renderer::Renderer,
+{
+    let Some(active_root) = state.active_root else { return; };
+    let Some(mut menu_tree) = menu_roots.get(active_root) else {
+        state.reset();
+        return;
+    };
+    let generation = state.generation;
+
+    for i in 0..state.menu_states.len() {
+        if menu_tree.children.is_empty() {
+            state.menu_states.truncate(i);
+            break;
+        }
+
+        // overlay space bounds of the item a submenu belongs to
+        let parent_bounds = match i.checked_sub(1).map(|p| &state.menu_states[p]) {
+            Some(parent) => {
+                let index = parent.index.expect("missing active child index in menu");
+                let mb = &parent.menu_bounds;
+                let item_position =
+                    Point::new(0.0, mb.child_positions[index] + parent.scroll_offset);
+                let item_size =
+                    get_item_size(menu_tree, mb.children_bounds.width, item_height);
+
+                Rectangle::new(item_position, item_size)
+                    + (mb.children_bounds.position() - Point::ORIGIN)
+            }
+            None => state.menu_states[i].menu_bounds.parent_bounds,
+        };
+
+        let ms = &mut state.menu_states[i];
+        if ms.generation != generation || ms.menu_bounds.parent_bounds != parent_bounds {
+            // root menus overlap horizontally, submenus vertically
+            let aod = Aod {
+                horizontal: true,
+                vertical: true,
+                horizontal_overlap: i == 0,
+                vertical_overlap: i != 0,
+                horizontal_direction: state.horizontal_direction,
+                vertical_direction: state.vertical_direction,
+            };
+
+            ms.menu_bounds = MenuBounds::new(
+                menu_tree,
+                item_width,
+                item_height,
+                state.viewport_size,
+                state.overlay_offset,
+                &aod,
+                bounds_expand,
+                parent_bounds,
+            );
+            ms.generation = generation;
+            ms.index = ms.index.filter(|&index| index < menu_tree.children.len());
+        }
+
+        let Some(index) = ms.index else {
+            state.menu_states.truncate(i + 1);
+            break;
+        };
+        menu_tree = &menu_tree.children[index];
+    }
+}
+
+fn process_type_ahead<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    c: char,
+    viewport_size: Size,
+    overlay_offset: Vector,
+) -> event::Status
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    use event::Status::{Captured, Ignored};
+
+    if c.is_control() {
+        return Ignored;
+    }
+
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let Some(active_root) = state.active_root else { return Ignored; };
+    let Some(last) = state.menu_states.len().checked_sub(1) else { return Ignored; };
+
+    state.type_ahead.push(c);
+
+    // only the last menu is searched, every menu before it has an active item
+    let active_menu = state.menu_states[..last]
+        .iter()
+        .fold(&menu.menu_roots[active_root], |mt, ms| {
+            &mt.children[ms.index.expect("missing active child index in menu")]
         });
+
+    let found = active_menu.children.iter().position(|mt| {
+        mt.label
+            .as_deref()
+            .map_or(false, |label| state.type_ahead.matches(label))
+    });
+
+    let Some(new_index) = found else { return Captured; };
+
+    let last_ms = &mut state.menu_states[last];
+    last_ms.index = Some(new_index);
+
+    // scroll the found item into view
+    let children_bounds = last_ms.menu_bounds.children_bounds + overlay_offset;
+    let item_height =
+        get_item_size(&active_menu.children[new_index], 0.0, menu.item_height).height;
+    let item_top = children_bounds.y
+        + last_ms.menu_bounds.child_positions[new_index]
+        + last_ms.scroll_offset;
+
+    if item_top < 0.0 {
+        last_ms.scroll_offset -= item_top;
+    } else if item_top + item_height > viewport_size.height {
+        last_ms.scroll_offset -= item_top + item_height - viewport_size.height;
     }
 
     Captured
@@ -1129,3 +1560,115 @@ fn search_bound<Message, Renderer>(
     }
     index
 }
+#[cfg(test)]
+mod tests {
+    use iced_native::{renderer::Null, widget::tree, widget::Space, Length};
+
+    use super::*;
+
+    type Item = MenuTree<'static, (), Null>;
+
+    impl StyleSheet for () {
+        type Style = ();
+
+        fn appearance(&self, _style: &Self::Style) -> Appearance {
+            Appearance::default()
+        }
+    }
+
+    fn item(label: Option<&str>) -> Item {
+        let item = MenuTree::new(Space::new(Length::Shrink, Length::Shrink));
+        match label {
+            Some(label) => item.label(label),
+            None => item,
+        }
+    }
+
+    fn typed(chars: &str) -> TypeAhead {
+        let mut type_ahead = TypeAhead::default();
+        chars.chars().for_each(|c| type_ahead.push(c));
+        type_ahead
+    }
+
+    #[test]
+    fn characters_accumulate_into_a_prefix() {
+        let type_ahead = typed("s");
+        assert!(type_ahead.matches("Save"));
+        assert!(type_ahead.matches("Select All"));
+
+        let type_ahead = typed("sa");
+        assert!(type_ahead.matches("Save"));
+        assert!(!type_ahead.matches("Select All"));
+    }
+
+    #[test]
+    fn matching_ignores_case() {
+        let type_ahead = typed("SaV");
+        assert!(type_ahead.matches("save"));
+        assert!(type_ahead.matches("SAVE AS"));
+    }
+
+    #[test]
+    fn prefix_starts_over_after_the_timeout() {
+        let mut type_ahead = typed("s");
+        type_ahead.last_input = type_ahead
+            .last_input
+            .map(|last| last - TypeAhead::TIMEOUT - Duration::from_millis(1));
+        type_ahead.push('c');
+
+        assert!(type_ahead.matches("Cut"));
+        assert!(!type_ahead.matches("Save"));
+    }
+
+    #[test]
+    fn type_ahead_skips_control_characters_and_unlabeled_items() {
+        let mut menu_roots = vec![MenuTree::with_children(
+            Space::new(Length::Shrink, Length::Shrink),
+            vec![item(None), item(Some("Cut")), item(Some("Copy"))],
+        )];
+
+        let mut state = MenuBarState::opened(&[0]);
+        state.menu_states[0].menu_bounds.child_positions = vec![0.0, 30.0, 60.0];
+        let mut tree = Tree {
+            tag: tree::Tag::of::<MenuBarState>(),
+            state: tree::State::new(state),
+            children: Vec::new(),
+        };
+
+        let mut menu = Menu {
+            tree: &mut tree,
+            menu_roots: &mut menu_roots,
+            bounds_expand: 0,
+            close_condition: CloseCondition {
+                leave: false,
+                click_outside: true,
+                click_inside: true,
+            },
+            item_width: ItemWidth::Uniform(100),
+            item_height: ItemHeight::Uniform(30),
+            bar_bounds: Rectangle::new(Point::ORIGIN, Size::ZERO),
+            root_bounds_list: Vec::new(),
+            path_highlight: None,
+            tooltip_delay: Duration::ZERO,
+            style: &(),
+        };
+        let viewport = Size::new(800.0, 600.0);
+        let offset = Vector::new(0.0, 0.0);
+        let active = |menu: &Menu<'_, '_, (), Null>| {
+            menu.tree.state.downcast_ref::<MenuBarState>().menu_states[0].index
+        };
+
+        // the unlabeled first item can't be found by typing
+        let status = process_type_ahead(&mut menu, 'c', viewport, offset);
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(active(&menu), Some(1));
+
+        // a backspace neither extends nor clears the prefix
+        let status = process_type_ahead(&mut menu, '\u{8}', viewport, offset);
+        assert_eq!(status, event::Status::Ignored);
+        assert_eq!(active(&menu), Some(1));
+
+        process_type_ahead(&mut menu, 'o', viewport, offset);
+        assert_eq!(active(&menu), Some(2));
+    }
+}
@@ -1,6 +1,16 @@
 //! A tree structure for constructing a hierarchical menu
 
 use iced_native::{renderer, Element};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A step from a menu tree to one of its children,
+/// keyed children are reached by key, the rest by position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum Step {
+    Key(u64),
+    Position(usize),
+}
 
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 /// a menu itself can also be an item of another menu.
@@ -15,15 +25,29 @@ pub struct MenuTree<'a, Message, Renderer> {
     /// The menu tree will be flatten into a vector to build a linear widget tree,
     /// the `index` field is the index of the item in that vector
     pub(super) index: usize,
+    /// The stable identity of the menu tree among its siblings
+    pub(super) key: Option<u64>,
 
     /// The item of the menu tree
     pub(super) item: Element<'a, Message, Renderer>,
     /// The children of the menu tree
-    pub(super) children: Vec<MenuTree<'a, Message, Renderer>>,
+    pub(crate) children: Vec<MenuTree<'a, Message, Renderer>>,
     /// The width of the menu tree
     pub(super) width: Option<u16>,
     /// The height of the menu tree
     pub(super) height: Option<u16>,
+    /// The searchable text label of the menu tree
+    pub(crate) label: Option<String>,
+    /// The checked state of the menu tree, if it's a check item
+    pub(crate) checked: Option<bool>,
+    /// The help text shown next to the item
+    pub(super) tooltip: Option<String>,
+    /// The message produced when the menu of the menu tree is opened
+    pub(super) on_open: Option<Box<dyn Fn() -> Message + 'a>>,
+    /// Builds the children of a lazy menu tree when its menu opens
+    pub(super) loader: Option<Box<dyn Fn() -> Vec<MenuTree<'a, Message, Renderer>> + 'a>>,
+    /// The item shown while the loader of a lazy menu tree returns no children
+    pub(super) placeholder: Option<Box<MenuTree<'a, Message, Renderer>>>,
 }
 impl<'a, Message, Renderer> MenuTree<'a, Message, Renderer>
 where
@@ -33,10 +57,17 @@ where
     pub fn new(item: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Self {
             index: 0,
+            key: None,
             item: item.into(),
             children: Vec::new(),
             width: None,
             height: None,
+            label: None,
+            checked: None,
+            tooltip: None,
+            on_open: None,
+            loader: None,
+            placeholder: None,
         }
     }
 
@@ -47,13 +78,67 @@ where
     ) -> Self {
         Self {
             index: 0,
+            key: None,
             item: item.into(),
             children: children.into_iter().map(Into::into).collect(),
             width: None,
             height: None,
+            label: None,
+            checked: None,
+            tooltip: None,
+            on_open: None,
+            loader: None,
+            placeholder: None,
         }
     }
 
+    /// Create a menu tree whose children are built by `loader` when its menu opens
+    ///
+    /// Until then the menu tree has no children, closed lazy menus are neither
+    /// flattened nor laid out. The children are built again after every view
+    /// while the menu stays open, add [`MenuTree::on_open`] to fetch their data
+    /// with a `Command` instead. The `placeholder` is shown as the only item
+    /// while `loader` returns no children, e.g. until that `Command` resolves
+    pub fn lazy<F>(
+        item: impl Into<Element<'a, Message, Renderer>>,
+        placeholder: impl Into<MenuTree<'a, Message, Renderer>>,
+        loader: F,
+    ) -> Self
+    where
+        F: 'a + Fn() -> Vec<MenuTree<'a, Message, Renderer>>,
+    {
+        let mut menu_tree = Self::new(item);
+        menu_tree.loader = Some(Box::new(loader));
+        menu_tree.placeholder = Some(Box::new(placeholder.into()));
+        menu_tree
+    }
+
+    /// Sets the message that is produced when the menu of the menu tree is opened
+    ///
+    /// This can be used to refresh children that change often
+    #[must_use]
+    pub fn on_open<F>(mut self, on_open: F) -> Self
+    where
+        F: 'a + Fn() -> Message,
+    {
+        self.on_open = Some(Box::new(on_open));
+        self
+    }
+
+    /// Sets the key of the menu tree.
+    ///
+    /// Keys identify a menu tree among its siblings across views, they should be
+    /// unique within a menu, items of different menus may share keys.
+    /// Keyed menu trees keep their widget state and stay open
+    /// when items are inserted, removed or moved around them
+    #[must_use]
+    pub fn key(mut self, key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        self.key = Some(hasher.finish());
+        self
+    }
+
     /// Sets the width of the menu tree.
     /// See [`ItemWidth`]
     ///
@@ -74,6 +159,59 @@ where
         self
     }
 
+    /// Sets the text label of the menu tree.
+    ///
+    /// The label is used for type-ahead selection, typing the first letters
+    /// of a label while a menu is open moves the active item to it
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Marks the menu tree as a check item with the given state.
+    ///
+    /// This is only used to describe the item to assistive technologies,
+    /// the item widget is still responsible for drawing the check mark
+    #[must_use]
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets the tooltip of the menu tree.
+    ///
+    /// The tooltip is shown next to the item after it's been hovered for a while,
+    /// see [`MenuBar::tooltip_delay`] and [`MenuBar::on_hover`]
+    ///
+    /// [`MenuBar::tooltip_delay`]: `super::MenuBar::tooltip_delay`
+    /// [`MenuBar::on_hover`]: `super::MenuBar::on_hover`
+    #[must_use]
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Whether the menu tree is a menu, a lazy menu tree is one before it's loaded
+    pub(crate) fn has_menu(&self) -> bool {
+        !self.children.is_empty() || self.loader.is_some()
+    }
+
+    /// Builds the children of a lazy menu tree that hasn't been loaded yet,
+    /// falls back to the placeholder, returns whether any were added
+    pub(super) fn load(&mut self) -> bool {
+        match &self.loader {
+            Some(loader) if self.children.is_empty() => {
+                self.children = loader();
+                if self.children.is_empty() {
+                    self.children.extend(self.placeholder.take().map(|p| *p));
+                }
+                !self.children.is_empty()
+            }
+            _ => false,
+        }
+    }
+
     /* Keep `set_index()` and `flattern()` recurse in the same order */
 
     /// Set the index of each item
@@ -96,11 +234,11 @@ where
     }
 
     /// Flatten the menu tree
-    pub(super) fn flattern(&'a self) -> Vec<&Self> {
+    pub(super) fn flattern(&self) -> Vec<&Self> {
         /// Inner flattening function
-        fn rec<'a, Message, Renderer>(
-            mt: &'a MenuTree<'a, Message, Renderer>,
-            flat: &mut Vec<&MenuTree<'a, Message, Renderer>>,
+        fn rec<'a, 'b, Message, Renderer>(
+            mt: &'b MenuTree<'a, Message, Renderer>,
+            flat: &mut Vec<&'b MenuTree<'a, Message, Renderer>>,
         ) {
             mt.children.iter().for_each(|c| {
                 flat.push(c);
@@ -117,6 +255,41 @@ where
 
         flat
     }
+
+    /// The steps from the menu tree to each of its descendants,
+    /// in the same order as [`MenuTree::flattern`]
+    ///
+    /// The steps of the parents keep equal keys in different menus apart
+    pub(super) fn flat_ids(&self) -> Vec<Vec<Step>> {
+        /// Inner collecting function
+        fn rec<Message, Renderer>(
+            mt: &MenuTree<'_, Message, Renderer>,
+            path: &[Step],
+            flat: &mut Vec<Vec<Step>>,
+        ) {
+            let paths = mt
+                .children
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    let mut p = path.to_vec();
+                    p.push(c.key.map_or(Step::Position(i), Step::Key));
+                    p
+                })
+                .collect::<Vec<_>>();
+            flat.extend(paths.iter().cloned());
+
+            mt.children
+                .iter()
+                .zip(&paths)
+                .for_each(|(c, p)| rec(c, p, flat));
+        }
+
+        let mut flat = vec![Vec::new()];
+        rec(self, &[], &mut flat);
+
+        flat
+    }
 }
 
 impl<'a, Message, Renderer> From<Element<'a, Message, Renderer>> for MenuTree<'a, Message, Renderer>
@@ -126,4 +299,96 @@ where
     fn from(value: Element<'a, Message, Renderer>) -> Self {
         Self::new(value)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use iced_native::{renderer::Null, widget::Space, Length};
+
+    use super::*;
+
+    type Tree = MenuTree<'static, (), Null>;
+
+    fn item() -> Tree {
+        MenuTree::new(Space::new(Length::Shrink, Length::Shrink))
+    }
+
+    fn menu(children: Vec<Tree>) -> Tree {
+        MenuTree::with_children(Space::new(Length::Shrink, Length::Shrink), children)
+    }
+
+    #[test]
+    fn flat_ids_follow_flattern_order() {
+        let root = menu(vec![menu(vec![item(), item()]), item()]);
+
+        assert_eq!(
+            root.flat_ids(),
+            vec![
+                vec![],
+                vec![Step::Position(0)],
+                vec![Step::Position(1)],
+                vec![Step::Position(0), Step::Position(0)],
+                vec![Step::Position(0), Step::Position(1)],
+            ]
+        );
+        assert_eq!(root.flat_ids().len(), root.flattern().len());
+    }
+
+    #[test]
+    fn equal_keys_in_different_menus_differ() {
+        let keyed = |children: Vec<Tree>| {
+            menu(
+                children
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, c)| c.key(i))
+                    .collect(),
+            )
+        };
+        let root = keyed(vec![keyed(vec![item()]), keyed(vec![item()])]);
+        let ids = root.flat_ids();
+
+        // both submenus key their only item with 0
+        assert_eq!(ids[3].last(), ids[4].last());
+        assert_ne!(ids[3], ids[4]);
+    }
+
+    #[test]
+    fn lazy_children_are_built_on_load() {
+        let mut root = menu(vec![
+            MenuTree::lazy(Space::new(Length::Shrink, Length::Shrink), item(), || {
+                vec![item(), item()]
+            }),
+            item(),
+        ]);
+
+        // closed lazy menus are skipped
+        assert_eq!(root.flattern().len(), 3);
+        assert_eq!(root.flat_ids().len(), 3);
+
+        assert!(root.children[0].load());
+        root.set_index();
+        assert_eq!(root.flattern().len(), 5);
+        assert_eq!(root.children[0].children[1].index, 4);
+
+        // loaded menus are kept until the next view
+        assert!(!root.children[0].load());
+        assert!(!root.children[1].load());
+    }
+
+    #[test]
+    fn lazy_menus_show_the_placeholder_while_loading() {
+        let mut root = menu(vec![MenuTree::lazy(
+            Space::new(Length::Shrink, Length::Shrink),
+            item().label("Loading"),
+            Vec::new,
+        )]);
+
+        assert!(root.children[0].has_menu());
+        assert!(root.children[0].load());
+        assert_eq!(root.children[0].children.len(), 1);
+        assert_eq!(
+            root.children[0].children[0].label.as_deref(),
+            Some("Loading")
+        );
+    }
+}
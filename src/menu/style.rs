@@ -3,6 +3,9 @@ use iced_native::Color;
 use iced_style::Theme;
 
 /// The appearance of a menu bar and its menus.
+///
+/// The tooltip colors were added after the other fields, struct literals written
+/// before have to set them or fill the rest with `..Appearance::default()`
 #[derive(Debug, Clone, Copy)]
 pub struct Appearance {
     /// The background color of the menu bar and its menus.
@@ -17,6 +20,10 @@ pub struct Appearance {
     pub background_expand: [u16; 4],
     /// The highlighted path [`Color`] of the the menu bar and its menus.
     pub path: Color,
+    /// The background [`Color`] of the tooltips.
+    pub tooltip_background: Color,
+    /// The text [`Color`] of the tooltips.
+    pub tooltip_text: Color,
 }
 impl std::default::Default for Appearance {
     fn default() -> Self {
@@ -27,6 +34,8 @@ impl std::default::Default for Appearance {
             border_color: Color::from([0.5; 3]),
             background_expand: [6; 4],
             path: Color::from([0.3; 3]),
+            tooltip_background: Color::from([0.95; 3]),
+            tooltip_text: Color::BLACK,
         }
     }
 }
@@ -78,6 +87,8 @@ impl StyleSheet for Theme {
                 border_color: palette.background.weak.color,
                 background_expand: [6; 4],
                 path: palette.primary.weak.color,
+                tooltip_background: palette.background.weak.color,
+                tooltip_text: palette.background.weak.text,
             },
             MenuBarStyle::Custom(c) => c.appearance(self),
         }
@@ -5,6 +5,7 @@ mod slider;
 mod svg;
 mod text;
 mod menu_bar;
+mod window;
 
 pub use application::Application;
 pub use button::Button;
@@ -14,6 +15,7 @@ pub use slider::Slider;
 pub use svg::Svg;
 pub use text::Text;
 pub use menu_bar::MenuBarStyle;
+pub use window::WindowStyle;
 
 /// color macro for use rgb or rgba with 255 instead of 1
 #[macro_export]
@@ -25,6 +25,8 @@ impl StyleSheet for Theme {
                 border_color: Color::TRANSPARENT,
                 background_expand: [6; 4],
                 path: Color::TRANSPARENT,
+                tooltip_background: self.currant_line,
+                tooltip_text: self.foreground,
             },
         }
     }
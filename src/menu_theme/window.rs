@@ -0,0 +1,129 @@
+use iced_graphics::Color;
+
+use crate::color;
+use crate::window::{
+    Appearance, FrameAppearance, StatusBarAppearance, StyleSheet, TitleBarAppearance, TitleButton,
+};
+
+use super::Theme;
+
+/// The style of the window chrome
+#[derive(Default, Clone)]
+#[allow(missing_debug_implementations)]
+pub enum WindowStyle {
+    /// The default style.
+    #[default]
+    Default,
+}
+
+impl StyleSheet for Theme {
+    type Style = WindowStyle;
+
+    fn frame(&self, style: &Self::Style) -> FrameAppearance {
+        match style {
+            WindowStyle::Default => FrameAppearance {
+                background: self.background,
+                shadow: color!(0, 0, 0, 0.5),
+                border: color!(78, 77, 83),
+            },
+        }
+    }
+
+    fn inactive_frame(&self, style: &Self::Style) -> FrameAppearance {
+        let active = self.frame(style);
+
+        match style {
+            WindowStyle::Default => FrameAppearance {
+                shadow: color!(0, 0, 0, 0.3),
+                border: color!(58, 57, 63),
+                ..active
+            },
+        }
+    }
+
+    fn title_bar(&self, style: &Self::Style) -> TitleBarAppearance {
+        match style {
+            WindowStyle::Default => TitleBarAppearance {
+                background: self.background,
+                title: self.foreground,
+            },
+        }
+    }
+
+    fn inactive_title_bar(&self, style: &Self::Style) -> TitleBarAppearance {
+        match style {
+            WindowStyle::Default => TitleBarAppearance {
+                background: self.background,
+                title: self.comment,
+            },
+        }
+    }
+
+    fn status_bar(&self, style: &Self::Style) -> StatusBarAppearance {
+        match style {
+            WindowStyle::Default => StatusBarAppearance {
+                background: self.background,
+                text: self.foreground,
+                grip: color!(78, 77, 83),
+            },
+        }
+    }
+
+    fn inactive_status_bar(&self, style: &Self::Style) -> StatusBarAppearance {
+        let active = self.status_bar(style);
+
+        match style {
+            WindowStyle::Default => StatusBarAppearance {
+                text: self.comment,
+                ..active
+            },
+        }
+    }
+
+    fn active(&self, style: &Self::Style, _button: TitleButton) -> Appearance {
+        match style {
+            WindowStyle::Default => Appearance {
+                background: Color::TRANSPARENT,
+                border_radius: 0.0,
+                icon: Some(self.svg),
+            },
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let active = self.active(style, button);
+
+        match button {
+            TitleButton::Close => Appearance {
+                background: color!(163, 55, 55),
+                ..active
+            },
+            _ => Appearance {
+                background: color!(78, 77, 83),
+                ..active
+            },
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let active = self.active(style, button);
+
+        match button {
+            TitleButton::Close => Appearance {
+                background: color!(241, 111, 122),
+                ..active
+            },
+            _ => Appearance {
+                background: color!(101, 100, 105),
+                ..active
+            },
+        }
+    }
+
+    fn inactive(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        Appearance {
+            icon: Some(self.comment),
+            ..self.active(style, button)
+        }
+    }
+}
@@ -1,42 +1,227 @@
-use iced_native::{event, mouse, overlay, touch, Color, Element, Event, Point, Rectangle, Size};
+use iced_native::{
+    event, mouse, overlay, touch, Color, Element, Event, Point, Rectangle, Size, Vector,
+};
 use iced_native::{
     renderer,
     widget::{Operation, Tree},
     Clipboard, Layout, Shell, Widget,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+/// The edges of the window that can be dragged to resize it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edges {
+    /// Whether the top edge can be dragged
+    pub top: bool,
+    /// Whether the bottom edge can be dragged
+    pub bottom: bool,
+    /// Whether the left edge can be dragged
+    pub left: bool,
+    /// Whether the right edge can be dragged
+    pub right: bool,
+}
+
+impl Edges {
+    /// Every edge can be dragged
+    pub const ALL: Self = Self {
+        top: true,
+        bottom: true,
+        left: true,
+        right: true,
+    };
+
+    /// No edge can be dragged
+    pub const NONE: Self = Self {
+        top: false,
+        bottom: false,
+        left: false,
+        right: false,
+    };
+}
+
+impl Default for Edges {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Dragging {
     HorizontalRight,
     HorizontalLeft,
     VerticalTop,
     VerticalBottom,
-    Both,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
     None,
 }
 
+impl Dragging {
+    /// Finds the resize zone under the cursor, corners take precedence over edges
+    ///
+    /// Edges are `border` thick, corners reach `corner` along both of their edges
+    fn hit(bounds: Rectangle, cursor: Point, border: f32, corner: f32, edges: Edges) -> Self {
+        // how far the cursor is from each edge
+        let left = cursor.x - bounds.x;
+        let right = bounds.x + bounds.width - cursor.x;
+        let top = cursor.y - bounds.y;
+        let bottom = bounds.y + bounds.height - cursor.y;
+
+        let corner_hit = |horizontal: f32, vertical: f32, enabled: bool| {
+            enabled
+                && ((horizontal < border && vertical < corner)
+                    || (vertical < border && horizontal < corner))
+        };
+
+        if corner_hit(left, top, edges.left && edges.top) {
+            Self::TopLeft
+        } else if corner_hit(right, top, edges.right && edges.top) {
+            Self::TopRight
+        } else if corner_hit(left, bottom, edges.left && edges.bottom) {
+            Self::BottomLeft
+        } else if corner_hit(right, bottom, edges.right && edges.bottom) {
+            Self::BottomRight
+        } else if edges.left && left < border {
+            Self::HorizontalLeft
+        } else if edges.right && right < border {
+            Self::HorizontalRight
+        } else if edges.top && top < border {
+            Self::VerticalTop
+        } else if edges.bottom && bottom < border {
+            Self::VerticalBottom
+        } else {
+            Self::None
+        }
+    }
+
+    fn left(self) -> bool {
+        matches!(self, Self::HorizontalLeft | Self::TopLeft | Self::BottomLeft)
+    }
+
+    fn right(self) -> bool {
+        matches!(self, Self::HorizontalRight | Self::TopRight | Self::BottomRight)
+    }
+
+    fn top(self) -> bool {
+        matches!(self, Self::VerticalTop | Self::TopLeft | Self::TopRight)
+    }
+
+    fn bottom(self) -> bool {
+        matches!(self, Self::VerticalBottom | Self::BottomLeft | Self::BottomRight)
+    }
+
+    fn direction(self) -> Option<ResizeDirection> {
+        match self {
+            Self::HorizontalRight => Some(ResizeDirection::East),
+            Self::HorizontalLeft => Some(ResizeDirection::West),
+            Self::VerticalTop => Some(ResizeDirection::North),
+            Self::VerticalBottom => Some(ResizeDirection::South),
+            Self::TopLeft => Some(ResizeDirection::NorthWest),
+            Self::TopRight => Some(ResizeDirection::NorthEast),
+            Self::BottomLeft => Some(ResizeDirection::SouthWest),
+            Self::BottomRight => Some(ResizeDirection::SouthEast),
+            Self::None => None,
+        }
+    }
+
+    /// The cursor shown over the resize zone
+    ///
+    /// `mouse::Interaction` has no diagonal resize cursors in this version of iced,
+    /// the corners show a crosshair instead
+    fn interaction(self) -> Option<mouse::Interaction> {
+        match self {
+            Self::HorizontalLeft | Self::HorizontalRight => {
+                Some(mouse::Interaction::ResizingHorizontally)
+            }
+            Self::VerticalTop | Self::VerticalBottom => Some(mouse::Interaction::ResizingVertically),
+            Self::TopLeft | Self::TopRight | Self::BottomLeft | Self::BottomRight => {
+                Some(mouse::Interaction::Crosshair)
+            }
+            Self::None => None,
+        }
+    }
+}
+
 struct ResizeState {
     dragging: Dragging,
-    window_size: Size,
-    window_position: Point,
-    show: bool,
+    /// The window bounds when the drag started
+    start_bounds: Rectangle,
+    /// The cursor position when the drag started, in window coordinates
+    start_cursor: Point,
+    /// How far the window has moved since the drag started
+    moved: Vector,
+    /// The bounds that were published last
+    published: Rectangle,
+    /// The window position, unknown until the first `Moved` event
+    window_position: Option<Point>,
 }
 
 impl Default for ResizeState {
     fn default() -> Self {
         Self {
             dragging: Dragging::None,
-            window_size: Size::new(0.0, 0.0),
-            window_position: Point::new(0.0, 0.0),
-            show: true,
+            start_bounds: Rectangle::default(),
+            start_cursor: Point::ORIGIN,
+            moved: Vector::new(0.0, 0.0),
+            published: Rectangle::default(),
+            window_position: None,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+/// Computes the window bounds for a drag that started with the window at `start`
+/// and has since moved the cursor by `delta` in screen space
+fn resized_bounds(dragging: Dragging, start: Rectangle, delta: Vector) -> Rectangle {
+    let mut bounds = start;
+
+    if dragging.left() {
+        bounds.x += delta.x;
+        bounds.width -= delta.x;
+    } else if dragging.right() {
+        bounds.width += delta.x;
+    }
+
+    if dragging.top() {
+        bounds.y += delta.y;
+        bounds.height -= delta.y;
+    } else if dragging.bottom() {
+        bounds.height += delta.y;
+    }
+
+    bounds
+}
+
+/// The edge or corner of the window that is being dragged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeDirection {
+    North,
+    South,
+    East,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+/// How the window is resized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeMode {
+    /// The widget follows the cursor and publishes a new size on every move
+    #[default]
+    Manual,
+    /// The widget publishes a single [`ResizeEvent::DragResize`] on press,
+    /// the windowing layer is expected to take over the resize from there
+    Native,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ResizeEvent {
     ResizeXY(Size),
     ResizeWindow(Rectangle),
+    /// Begin an interactive resize in the given direction, see [`ResizeMode::Native`]
+    DragResize(ResizeDirection),
 }
 
 pub fn resize<'a, Message, Renderer, F: 'a + Fn(ResizeEvent) -> Message>(
@@ -49,6 +234,16 @@ pub fn resize<'a, Message, Renderer, F: 'a + Fn(ResizeEvent) -> Message>(
 pub struct Resize<'a, Message, Renderer> {
     element: Element<'a, Message, Renderer>,
     handle_events: Box<dyn Fn(ResizeEvent) -> Message + 'a>,
+    min_size: Size,
+    max_size: Size,
+    aspect_ratio: Option<f32>,
+    border: f32,
+    corner: f32,
+    grip: Size,
+    edges: Edges,
+    show: bool,
+    mode: ResizeMode,
+    initial_position: Option<Point>,
 }
 
 impl<'a, Message, Renderer> Resize<'a, Message, Renderer> {
@@ -59,7 +254,158 @@ impl<'a, Message, Renderer> Resize<'a, Message, Renderer> {
         Self {
             element: element.into(),
             handle_events: Box::new(event_handler),
+            min_size: Size::new(1.0, 1.0),
+            max_size: Size::INFINITY,
+            aspect_ratio: None,
+            border: 5.0,
+            corner: 10.0,
+            grip: Size::ZERO,
+            edges: Edges::ALL,
+            show: true,
+            mode: ResizeMode::Manual,
+            initial_position: None,
+        }
+    }
+
+    /// Sets where the window was opened
+    ///
+    /// Left and top edges can only be dragged once the window position is known,
+    /// this is used until the first `Moved` event is received
+    pub fn initial_position(mut self, position: Point) -> Self {
+        self.initial_position = Some(position);
+        self
+    }
+
+    /// Sets how the window is resized, see [`ResizeMode`]
+    pub fn mode(mut self, mode: ResizeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the thickness of the resize zone along the edges
+    pub fn border(mut self, border: f32) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets how far the corner resize zones reach along their edges
+    pub fn corner(mut self, corner: f32) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the size of an area in the bottom right corner that resizes the window
+    /// diagonally, like the resize grip of a status bar
+    pub fn grip(mut self, grip: Size) -> Self {
+        self.grip = grip;
+        self
+    }
+
+    /// Sets which edges can be dragged, corners need both of their edges
+    pub fn edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// Sets whether the window can be resized at all, e.g. it shouldn't be while maximized
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = show;
+        self
+    }
+
+    fn hit(&self, state: &ResizeState, bounds: Rectangle, cursor_position: Point) -> Dragging {
+        let mut edges = self.edges;
+
+        // moving the window needs to know where it is
+        if self.mode == ResizeMode::Manual
+            && state.window_position.or(self.initial_position).is_none()
+        {
+            edges.left = false;
+            edges.top = false;
+        }
+
+        let right = bounds.x + bounds.width - cursor_position.x;
+        let bottom = bounds.y + bounds.height - cursor_position.y;
+        if edges.right
+            && edges.bottom
+            && (0.0..self.grip.width).contains(&right)
+            && (0.0..self.grip.height).contains(&bottom)
+        {
+            return Dragging::BottomRight;
+        }
+
+        Dragging::hit(bounds, cursor_position, self.border, self.corner, edges)
+    }
+
+    /// Sets the smallest size the window can be resized to
+    pub fn min_size(mut self, min_size: Size) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the largest size the window can be resized to
+    pub fn max_size(mut self, max_size: Size) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Keeps the width divided by the height of the window at `aspect_ratio`,
+    /// ratios that aren't positive and finite are ignored
+    pub fn aspect_ratio(mut self, aspect_ratio: Option<f32>) -> Self {
+        self.aspect_ratio = aspect_ratio.filter(|ratio| ratio.is_finite() && *ratio > 0.0);
+        self
+    }
+
+    /// Applies the size constraints to the resized bounds,
+    /// the edges opposite to the dragged ones stay in place
+    fn constrain(&self, dragging: Dragging, original: Rectangle, resized: Rectangle) -> Rectangle {
+        // unlike `f32::clamp` this doesn't panic when the limits cross
+        let clamp = |value: f32, min: f32, max: f32| value.max(min).min(max);
+
+        let mut size = Size::new(
+            clamp(resized.width, self.min_size.width, self.max_size.width),
+            clamp(resized.height, self.min_size.height, self.max_size.height),
+        );
+
+        if let Some(ratio) = self.aspect_ratio {
+            let horizontal = dragging.left() || dragging.right();
+            let vertical = dragging.top() || dragging.bottom();
+
+            // the dragged axis leads, on corners the one that changed the most
+            let width_leads = match (horizontal, vertical) {
+                (true, false) => true,
+                (false, true) => false,
+                _ => {
+                    (size.width - original.width).abs() / ratio
+                        >= (size.height - original.height).abs()
+                }
+            };
+
+            let width = if width_leads {
+                size.width
+            } else {
+                size.height * ratio
+            };
+            let width = clamp(
+                width,
+                self.min_size.width.max(self.min_size.height * ratio),
+                self.max_size.width.min(self.max_size.height * ratio),
+            );
+            size = Size::new(width, width / ratio);
         }
+
+        let x = if dragging.left() {
+            original.x + original.width - size.width
+        } else {
+            original.x
+        };
+        let y = if dragging.top() {
+            original.y + original.height - size.height
+        } else {
+            original.y
+        };
+
+        Rectangle::new(Point::new(x, y), size)
     }
 }
 
@@ -118,52 +464,41 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        let mut state = tree.state.downcast_mut::<ResizeState>();
-        let mut bounds = Rectangle::new(state.window_position, state.window_size);
+        let state = tree.state.downcast_mut::<ResizeState>();
 
         match event {
-            Event::Window(ref event) => match event {
-                iced_native::window::Event::Resized { width, height } => {
-                    state.window_size = Size::new(width.clone() as f32, height.clone() as f32);
-                }
-                iced_native::window::Event::Moved { x, y } => {
-                    state.window_position = Point::new(x.clone() as f32, y.clone() as f32);
+            Event::Window(iced_native::window::Event::Moved { x, y }) => {
+                let position = Point::new(x as f32, y as f32);
+                state.window_position = Some(position);
+
+                if state.dragging != Dragging::None {
+                    state.moved = position - state.start_bounds.position();
                 }
-                _ => {}
-            },
+            }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
-                let mut size = bounds.size();
-                let Point { x, y } = cursor_position;
-
-                match state.dragging {
-                    Dragging::HorizontalRight => {
-                        if x < bounds.width || x > bounds.width {
-                            size.width = x;
-                            shell.publish((self.handle_events)(ResizeEvent::ResizeXY(size)));
-                        }
-                    }
-                    Dragging::VerticalBottom => {
-                        if y < bounds.height || y > bounds.y {
-                            size.height = y;
-                            shell.publish((self.handle_events)(ResizeEvent::ResizeXY(size)));
-                        }
-                    }
-                    Dragging::HorizontalLeft => {
-                        if x < bounds.x || x > bounds.x {
-                            bounds.width = bounds.width - x;
-                            bounds.x = bounds.x + x;
-                            shell.publish((self.handle_events)(ResizeEvent::ResizeWindow(bounds)));
-                        }
-                    }
-                    Dragging::VerticalTop => {
-                        if y < bounds.y || y > bounds.y {
-                            bounds.height = bounds.height - y;
-                            bounds.y = bounds.y + y;
-                            shell.publish((self.handle_events)(ResizeEvent::ResizeWindow(bounds)));
-                        }
+                if self.show && state.dragging != Dragging::None {
+                    let dragging = state.dragging;
+                    let start = state.start_bounds;
+
+                    // the cursor is in window space and the window may have moved since
+                    // the drag started, so the delta accounts for that movement
+                    let delta = state.moved + (cursor_position - state.start_cursor);
+                    let new_bounds =
+                        self.constrain(dragging, start, resized_bounds(dragging, start, delta));
+
+                    if new_bounds != state.published {
+                        state.published = new_bounds;
+
+                        let event = if dragging.left() || dragging.top() {
+                            // assume the window ends up where it's asked to
+                            state.moved = new_bounds.position() - start.position();
+                            ResizeEvent::ResizeWindow(new_bounds)
+                        } else {
+                            ResizeEvent::ResizeXY(new_bounds.size())
+                        };
+                        shell.publish((self.handle_events)(event));
                     }
-                    _ => {}
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -175,22 +510,28 @@ where
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if state.show {
+                if self.show {
                     let bounds = layout.bounds();
+                    let dragging = self.hit(state, bounds, cursor_position);
 
-                    let Point { x, y } = cursor_position;
+                    if let Some(direction) = dragging.direction() {
+                        match self.mode {
+                            ResizeMode::Manual => {
+                                let position = state
+                                    .window_position
+                                    .or(self.initial_position)
+                                    .unwrap_or(Point::ORIGIN);
 
-                    if x < bounds.x + 5.0 {
-                        state.dragging = Dragging::HorizontalLeft;
-                        return event::Status::Captured;
-                    } else if x > bounds.x + bounds.width - 5.0 {
-                        state.dragging = Dragging::HorizontalRight;
-                        return event::Status::Captured;
-                    } else if y < bounds.y + 5.0 {
-                        state.dragging = Dragging::VerticalTop;
-                        return event::Status::Captured;
-                    } else if y > bounds.y + bounds.height - 5.0 {
-                        state.dragging = Dragging::VerticalBottom;
+                                state.dragging = dragging;
+                                state.start_bounds = Rectangle::new(position, bounds.size());
+                                state.start_cursor = cursor_position;
+                                state.moved = Vector::new(0.0, 0.0);
+                                state.published = state.start_bounds;
+                            }
+                            ResizeMode::Native => shell.publish((self.handle_events)(
+                                ResizeEvent::DragResize(direction),
+                            )),
+                        }
                         return event::Status::Captured;
                     }
                 }
@@ -263,22 +604,15 @@ where
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<ResizeState>();
 
-        if state.show {
-            let bounds = layout.bounds();
-
-            let Point { x, y } = cursor_position;
+        if self.show {
+            let dragging = if state.dragging == Dragging::None {
+                self.hit(state, layout.bounds(), cursor_position)
+            } else {
+                state.dragging
+            };
 
-            if x < bounds.x + 5.0 {
-                return mouse::Interaction::ResizingHorizontally;
-            }
-            if x > bounds.x + bounds.width - 5.0 {
-                return mouse::Interaction::ResizingHorizontally;
-            }
-            if y < bounds.y + 5.0 {
-                return mouse::Interaction::ResizingVertically;
-            }
-            if y > bounds.y + bounds.height - 5.0 {
-                return mouse::Interaction::ResizingVertically;
+            if let Some(interaction) = dragging.interaction() {
+                return interaction;
             }
         }
 
@@ -314,3 +648,269 @@ where
         Self::new(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iced_native::{clipboard, layout::Limits, renderer::Null, widget::Space, window, Length};
+
+    use super::*;
+
+    fn window() -> Resize<'static, (), Null> {
+        Resize::new(Space::new(Length::Fill, Length::Fill), |_| ())
+    }
+
+    fn rect(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    fn start() -> Rectangle {
+        rect(100.0, 100.0, 400.0, 300.0)
+    }
+
+    #[test]
+    fn each_edge_moves_only_itself() {
+        let delta = Vector::new(10.0, 20.0);
+        let cases = [
+            (Dragging::HorizontalLeft, rect(110.0, 100.0, 390.0, 300.0)),
+            (Dragging::HorizontalRight, rect(100.0, 100.0, 410.0, 300.0)),
+            (Dragging::VerticalTop, rect(100.0, 120.0, 400.0, 280.0)),
+            (Dragging::VerticalBottom, rect(100.0, 100.0, 400.0, 320.0)),
+            (Dragging::TopLeft, rect(110.0, 120.0, 390.0, 280.0)),
+            (Dragging::TopRight, rect(100.0, 120.0, 410.0, 280.0)),
+            (Dragging::BottomLeft, rect(110.0, 100.0, 390.0, 320.0)),
+            (Dragging::BottomRight, rect(100.0, 100.0, 410.0, 320.0)),
+            (Dragging::None, start()),
+        ];
+
+        for (dragging, expected) in cases {
+            let resized = resized_bounds(dragging, start(), delta);
+            assert_eq!(resized, expected, "{dragging:?}");
+        }
+    }
+
+    #[test]
+    fn min_size_keeps_the_opposite_edge() {
+        let window = window().min_size(Size::new(200.0, 100.0));
+
+        // the left edge dragged past the minimum stops short of it
+        let resized = resized_bounds(Dragging::HorizontalLeft, start(), Vector::new(350.0, 0.0));
+        assert_eq!(
+            window.constrain(Dragging::HorizontalLeft, start(), resized),
+            rect(300.0, 100.0, 200.0, 300.0)
+        );
+
+        let resized = resized_bounds(Dragging::VerticalTop, start(), Vector::new(0.0, 250.0));
+        assert_eq!(
+            window.constrain(Dragging::VerticalTop, start(), resized),
+            rect(100.0, 300.0, 400.0, 100.0)
+        );
+
+        let resized = resized_bounds(Dragging::BottomRight, start(), Vector::new(-350.0, -250.0));
+        assert_eq!(
+            window.constrain(Dragging::BottomRight, start(), resized),
+            rect(100.0, 100.0, 200.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn max_size_keeps_the_opposite_edge() {
+        let window = window().max_size(Size::new(500.0, 350.0));
+
+        let resized = resized_bounds(Dragging::TopLeft, start(), Vector::new(-200.0, -200.0));
+        assert_eq!(
+            window.constrain(Dragging::TopLeft, start(), resized),
+            rect(0.0, 50.0, 500.0, 350.0)
+        );
+
+        let resized = resized_bounds(Dragging::BottomRight, start(), Vector::new(200.0, 200.0));
+        assert_eq!(
+            window.constrain(Dragging::BottomRight, start(), resized),
+            rect(100.0, 100.0, 500.0, 350.0)
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_follows_the_dragged_axis() {
+        let window = window().aspect_ratio(Some(2.0));
+
+        let resized = resized_bounds(Dragging::HorizontalRight, start(), Vector::new(100.0, 0.0));
+        assert_eq!(
+            window.constrain(Dragging::HorizontalRight, start(), resized),
+            rect(100.0, 100.0, 500.0, 250.0)
+        );
+
+        let resized = resized_bounds(Dragging::VerticalTop, start(), Vector::new(0.0, 100.0));
+        assert_eq!(
+            window.constrain(Dragging::VerticalTop, start(), resized),
+            rect(100.0, 200.0, 400.0, 200.0)
+        );
+
+        // on corners the axis that changed the most leads
+        let resized = resized_bounds(Dragging::BottomRight, start(), Vector::new(20.0, 100.0));
+        assert_eq!(
+            window.constrain(Dragging::BottomRight, start(), resized),
+            rect(100.0, 100.0, 800.0, 400.0)
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_stays_within_the_limits() {
+        let window = window()
+            .aspect_ratio(Some(2.0))
+            .max_size(Size::new(600.0, 1000.0));
+
+        let resized = resized_bounds(Dragging::HorizontalLeft, start(), Vector::new(-400.0, 0.0));
+        assert_eq!(
+            window.constrain(Dragging::HorizontalLeft, start(), resized),
+            rect(-100.0, 100.0, 600.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn invalid_aspect_ratios_are_ignored() {
+        let resized = resized_bounds(Dragging::HorizontalRight, start(), Vector::new(100.0, 0.0));
+
+        for ratio in [0.0, -2.0, f32::NAN, f32::INFINITY] {
+            let window = window().aspect_ratio(Some(ratio));
+            assert_eq!(window.aspect_ratio, None, "{ratio}");
+            assert_eq!(
+                window.constrain(Dragging::HorizontalRight, start(), resized),
+                resized,
+                "{ratio}"
+            );
+        }
+    }
+
+    #[test]
+    fn left_and_top_edges_need_the_window_position() {
+        let bounds = rect(0.0, 0.0, 400.0, 300.0);
+        let state = ResizeState::default();
+        let left = Point::new(1.0, 150.0);
+        let top = Point::new(200.0, 1.0);
+
+        let window = window();
+        assert_eq!(window.hit(&state, bounds, left), Dragging::None);
+        assert_eq!(window.hit(&state, bounds, top), Dragging::None);
+
+        let window = window.initial_position(Point::new(100.0, 100.0));
+        assert_eq!(window.hit(&state, bounds, left), Dragging::HorizontalLeft);
+        assert_eq!(window.hit(&state, bounds, top), Dragging::VerticalTop);
+    }
+
+    fn press(x: f32, y: f32) -> (Event, Point) {
+        let event = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        (event, Point::new(x, y))
+    }
+
+    fn cursor(x: f32, y: f32) -> (Event, Point) {
+        let position = Point::new(x, y);
+        (
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        )
+    }
+
+    fn moved(x: i32, y: i32) -> (Event, Point) {
+        (Event::Window(window::Event::Moved { x, y }), Point::ORIGIN)
+    }
+
+    fn release() -> (Event, Point) {
+        let event = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        (event, Point::ORIGIN)
+    }
+
+    /// Feeds the events with their cursor positions to a 400x300 window,
+    /// returns the published resize events
+    fn drive(
+        resize: Resize<'static, ResizeEvent, Null>,
+        events: &[(Event, Point)],
+    ) -> Vec<ResizeEvent> {
+        let mut resize = resize;
+        let renderer = Null::new();
+        let node = resize.layout(&renderer, &Limits::new(Size::ZERO, Size::new(400.0, 300.0)));
+        let mut tree = Tree::new(&resize as &dyn Widget<ResizeEvent, Null>);
+
+        let mut published = Vec::new();
+        let mut shell = Shell::new(&mut published);
+        for (event, cursor_position) in events {
+            resize.on_event(
+                &mut tree,
+                event.clone(),
+                Layout::new(&node),
+                *cursor_position,
+                &renderer,
+                &mut clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        published
+    }
+
+    fn resizer() -> Resize<'static, ResizeEvent, Null> {
+        Resize::new(Space::new(Length::Fill, Length::Fill), |event| event)
+    }
+
+    #[test]
+    fn right_and_bottom_edges_resize_without_the_window_position() {
+        let published = drive(
+            resizer(),
+            &[
+                press(399.0, 299.0),
+                cursor(419.0, 309.0),
+                release(),
+                cursor(500.0, 500.0),
+            ],
+        );
+
+        assert_eq!(
+            published,
+            vec![ResizeEvent::ResizeXY(Size::new(420.0, 310.0))]
+        );
+    }
+
+    #[test]
+    fn left_edge_starts_once_a_moved_event_arrives() {
+        let published = drive(
+            resizer(),
+            &[
+                press(1.0, 150.0),
+                cursor(-9.0, 150.0),
+                release(),
+                moved(100, 100),
+                press(1.0, 150.0),
+                cursor(-9.0, 150.0),
+            ],
+        );
+
+        assert_eq!(
+            published,
+            vec![ResizeEvent::ResizeWindow(rect(90.0, 100.0, 410.0, 300.0))]
+        );
+    }
+
+    #[test]
+    fn left_edge_follows_the_window_with_and_without_moved_events() {
+        let first = ResizeEvent::ResizeWindow(rect(90.0, 100.0, 410.0, 300.0));
+        let second = ResizeEvent::ResizeWindow(rect(85.0, 100.0, 415.0, 300.0));
+
+        // the window is assumed to end up where it was asked to go
+        let published = drive(
+            resizer().initial_position(Point::new(100.0, 100.0)),
+            &[press(1.0, 150.0), cursor(-9.0, 150.0), cursor(-4.0, 150.0)],
+        );
+        assert_eq!(published, vec![first.clone(), second.clone()]);
+
+        // the cursor is reported relative to the window after it has moved
+        let published = drive(
+            resizer().initial_position(Point::new(100.0, 100.0)),
+            &[
+                press(1.0, 150.0),
+                cursor(-9.0, 150.0),
+                moved(90, 100),
+                cursor(-4.0, 150.0),
+            ],
+        );
+        assert_eq!(published, vec![first, second]);
+    }
+}
@@ -31,3 +31,59 @@ pub fn restore() -> svg::Handle {
 ,
     )
 }
+pub fn traffic_close() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='6' fill='#ff5f57' stroke='#e0443e' stroke-width='.5'/></svg>
+      "
+        .as_bytes(),
+    )
+}
+pub fn traffic_minimize() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='6' fill='#febc2e' stroke='#dea123' stroke-width='.5'/></svg>
+      "
+        .as_bytes(),
+    )
+}
+pub fn traffic_maximize() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='6' fill='#28c840' stroke='#1aab29' stroke-width='.5'/></svg>
+      "
+        .as_bytes(),
+    )
+}
+pub fn round_close() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='8' fill='currentColor' fill-opacity='.15'/><path fill='currentColor' d='M5.35 4.65L8 7.29l2.65-2.64l.7.7L8.71 8l2.64 2.65l-.7.7L8 8.71l-2.65 2.64l-.7-.7L7.29 8L4.65 5.35z'/></svg>
+      "
+        .as_bytes(),
+    )
+}
+pub fn round_minimize() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='8' fill='currentColor' fill-opacity='.15'/><path fill='currentColor' d='M4.5 10h7v1h-7z'/></svg>
+      "
+        .as_bytes(),
+    )
+}
+pub fn round_maximize() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='8' fill='currentColor' fill-opacity='.15'/><path fill='currentColor' d='M4.5 4.5v7h7v-7zm1 1h5v5h-5z'/></svg>
+      "
+        .as_bytes(),
+    )
+}
+pub fn round_restore() -> svg::Handle {
+    svg::Handle::from_memory(
+        "
+        <svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><circle cx='8' cy='8' r='8' fill='currentColor' fill-opacity='.15'/><path fill='currentColor' d='M4.5 6.5v5h5v-5zm1 1h3v3h-3z'/><path fill='currentColor' d='M6.5 4.5v1h4v4h1v-5z'/></svg>
+      "
+        .as_bytes(),
+    )
+}
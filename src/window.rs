@@ -1,24 +1,76 @@
 use iced_graphics::{Color, Rectangle};
 use iced_native::{
-    command, row,
-    widget::{button, container, svg, text},
-    window, Command, Element, Length,
+    command, subscription,
+    widget::{container, text, Column},
+    window, Command, Element, Event, Length, Point, Size, Subscription,
 };
 
 use crate::{
-    drag_window::DragWindow,
-    menu::{self, MenuBar, MenuTree},
+    menu::{self, MenuTree},
     menu_theme,
     resize::{resize, ResizeEvent},
-    svgs,
     theme_handler::{self, NewRenderer},
 };
 
-pub struct Window;
+mod background;
+mod button;
+mod frame;
+mod icon;
+mod preset;
+mod snap;
+mod status_bar;
+mod style;
+mod title_bar;
+
+use frame::Frame;
+pub(crate) use icon::system_menu_items;
+
+pub use frame::Decorations;
+pub use preset::{ButtonIcons, ButtonLayout, Preset};
+pub use snap::{snap_assist, SnapAssist, SnapZone};
+pub use status_bar::StatusBar;
+pub use style::{
+    Appearance, FrameAppearance, StatusBarAppearance, StyleSheet, TitleBarAppearance, WindowStyle,
+};
+pub use title_bar::{TitleBar, TitleButton};
+
+/// The window chrome, it keeps track of the window state the chrome depends on
+#[derive(Debug, Clone, Copy)]
+pub struct Window {
+    maximized: bool,
+    /// The size the window got when it was maximized, used to notice
+    /// the window manager restoring it when the work area isn't known
+    maximized_size: Option<Size>,
+    focused: bool,
+    /// Where the window is on the screen, needed to drag the left and top edges
+    position: Option<Point>,
+    /// The work area of the monitor, snap assist is on when it's known
+    work_area: Option<Rectangle>,
+    /// The client-side shadow and rounded corners, if any
+    decorations: Option<Decorations>,
+    /// The width of the outline around the window, `0.0` draws none
+    border: f32,
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self {
+            maximized: false,
+            maximized_size: None,
+            focused: true,
+            position: None,
+            work_area: None,
+            decorations: None,
+            border: 0.0,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub enum TitleEvents {
     Minimize,
+    Maximize,
+    /// Restore the window from being maximized
     Restore,
     Close,
 }
@@ -28,11 +80,115 @@ pub enum WindowEvents {
     ResizeEvent(ResizeEvent),
     TitleEvent(TitleEvents),
     DragWindow,
+    /// The title bar was clicked without moving the window
+    TitleClick,
+    /// The title bar was double clicked, this toggles maximize
+    TitleDoubleClick,
+    /// A move ended over a snap zone, the window takes up the given bounds
+    Snap(SnapZone, Rectangle),
+    /// The window gained or lost the focus, see [`Window::subscription`]
+    Focused(bool),
+    /// The window was resized, possibly maximized or restored by the window manager,
+    /// see [`Window::subscription`]
+    Resized(Size),
+    /// The window was moved to the given position, see [`Window::subscription`]
+    Moved(Point),
 }
 
 impl Window {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the window is maximized
+    ///
+    /// Maximizing by the window manager is only noticed when the work area is known,
+    /// see [`Window::snap_assist`], without it any resize of a maximized window restores it
+    pub fn is_maximized(&self) -> bool {
+        self.maximized
+    }
+
+    /// Whether the window has the focus
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Listens to the window events the chrome depends on, like focus and size changes
+    ///
+    /// The events have to be handed to [`Window::event_handler`]
+    pub fn subscription() -> Subscription<WindowEvents> {
+        subscription::events_with(|event, _status| match event {
+            Event::Window(window::Event::Focused) => Some(WindowEvents::Focused(true)),
+            Event::Window(window::Event::Unfocused) => Some(WindowEvents::Focused(false)),
+            Event::Window(window::Event::Resized { width, height }) => Some(WindowEvents::Resized(
+                Size::new(width as f32, height as f32),
+            )),
+            Event::Window(window::Event::Moved { x, y }) => {
+                Some(WindowEvents::Moved(Point::new(x as f32, y as f32)))
+            }
+            _ => None,
+        })
+    }
+
+    /// Sets where the window opens on the screen
+    ///
+    /// The left and top edges can only be dragged once the window position is known,
+    /// some platforms only report it after the window is first moved
+    #[must_use]
+    pub fn position(mut self, position: Point) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Turns on snap assist for a monitor with the given work area
+    ///
+    /// iced can't query the monitors, so the work area has to come from the application
+    #[must_use]
+    pub fn snap_assist(mut self, work_area: Rectangle) -> Self {
+        self.work_area = Some(work_area);
+        self
+    }
+
+    /// Updates the work area snap assist uses, `None` turns it off
+    pub fn set_work_area(&mut self, work_area: Option<Rectangle>) {
+        self.work_area = work_area;
+    }
+
+    /// Draws a shadow and rounded corners around the window, see [`Decorations`]
+    ///
+    /// The window has to be created transparent and without native decorations,
+    /// the resize zones are placed in the margin holding the shadow
+    #[must_use]
+    pub fn decorations(mut self, decorations: Decorations) -> Self {
+        self.decorations = Some(decorations);
+        self
+    }
+
+    /// Updates the client-side decorations, `None` turns them off
+    pub fn set_decorations(&mut self, decorations: Option<Decorations>) {
+        self.decorations = decorations;
+    }
+
+    /// Draws an outline of the given width around the window
+    ///
+    /// The outline is drawn over the edges of the content, it takes no space from it
+    /// and doesn't change where the window can be resized
+    #[must_use]
+    pub fn border(mut self, width: f32) -> Self {
+        self.border = width;
+        self
+    }
+
+    /// Updates the width of the outline around the window, `0.0` turns it off
+    pub fn set_border(&mut self, width: f32) {
+        self.border = width;
+    }
+
+    /// Builds the window chrome with a menu bar around the content of the window
     pub fn view<'a, Message, Renderer, F>(
+        &self,
         menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
+        content: impl Into<Element<'a, Message, Renderer>>,
         event_handler: F,
         title: Option<&'a str>,
         title_color: Option<Color>,
@@ -42,52 +198,113 @@ impl Window {
             iced_native::Renderer + iced_native::svg::Renderer + iced_native::text::Renderer + 'a,
         Renderer::Theme: iced_native::widget::container::StyleSheet
             + menu::StyleSheet
+            + StyleSheet
+            + iced_native::widget::button::StyleSheet
+            + iced_native::widget::svg::StyleSheet
+            + iced_native::widget::text::StyleSheet,
+        Message: 'a + Clone,
+        F: 'a + Clone + Fn(WindowEvents) -> Message,
+    {
+        let mut title_bar = TitleBar::new().menu_roots(menu_roots);
+        if let Some(title) = title {
+            title_bar = title_bar.title(title);
+        }
+        if let Some(title_color) = title_color {
+            title_bar = title_bar.title_color(title_color);
+        }
+
+        self.view_with(title_bar, content, None, event_handler)
+    }
+
+    /// Builds the window chrome around the content of the window
+    /// from a configured [`TitleBar`] and an optional [`StatusBar`]
+    pub fn view_with<'a, Message, Renderer, F>(
+        &self,
+        title_bar: TitleBar<'a, Message, Renderer>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        status_bar: Option<StatusBar<'a, Message, Renderer>>,
+        event_handler: F,
+    ) -> iced_native::Element<'a, Message, Renderer>
+    where
+        Renderer:
+            iced_native::Renderer + iced_native::svg::Renderer + iced_native::text::Renderer + 'a,
+        Renderer::Theme: iced_native::widget::container::StyleSheet
+            + menu::StyleSheet
+            + StyleSheet
             + iced_native::widget::button::StyleSheet
-            + iced_native::widget::svg::StyleSheet,
+            + iced_native::widget::svg::StyleSheet
+            + iced_native::widget::text::StyleSheet,
         Message: 'a + Clone,
         F: 'a + Clone + Fn(WindowEvents) -> Message,
     {
         let event_handler2 = event_handler.clone();
-        let title_bar_buttons = row![
-            button(svg(svgs::minimize_svg()).height(30.0))
-                .width(50.0)
-                // .style(menu_theme::Button::OtherMenu)
-                .on_press((event_handler)(WindowEvents::TitleEvent(
-                    TitleEvents::Minimize
-                ))),
-            button(svg(svgs::restore()).height(30.0))
-                .width(50.0)
-                // .style(menu_theme::Button::OtherMenu)
-                .on_press((event_handler)(WindowEvents::TitleEvent(
-                    TitleEvents::Restore
-                ))),
-            button(svg(svgs::close_svg()).height(30.0))
-                .width(50.0)
-                // .style(menu_theme::Button::Close)
-                .on_press((event_handler)(WindowEvents::TitleEvent(
-                    TitleEvents::Close
-                ))),
-        ];
-
-        let window = resize(
-            container(
-                container(row![
-                    MenuBar::new(menu_roots).close_condition(menu::CloseCondition {
-                        leave: false,
-                        click_outside: true,
-                        click_inside: true
-                    }),
-                    DragWindow::with_width(Length::Fill, (event_handler)(WindowEvents::DragWindow))
-                        .set_title(title)
-                        .set_title_color(title_color),
-                    title_bar_buttons
-                ])
-                .height(35.0),
-            )
+        let event_handler3 = event_handler.clone();
+        // a maximized window fills the screen, it has no shadow or rounded corners
+        let decorations = self.decorations.filter(|_| !self.maximized);
+        let mut title_bar = title_bar.focused(self.focused);
+        if let Some(decorations) = decorations {
+            title_bar = title_bar.corner_radius(decorations.corner_radius);
+        }
+        let style = title_bar.chrome_style();
+        let mut column = Column::new()
             .width(Length::Fill)
-            .height(Length::Fill),
-            move |e| (event_handler2)(WindowEvents::ResizeEvent(e)),
-        );
+            .height(Length::Fill)
+            .push(title_bar.view(self.maximized, event_handler))
+            .push(container(content).width(Length::Fill).height(Length::Fill));
+
+        // the grip sits in the bottom right corner of the content, inside the margin
+        let mut grip = None;
+        if let Some(mut status_bar) = status_bar {
+            status_bar = status_bar.focused(self.focused);
+            if let Some(decorations) = decorations {
+                status_bar = status_bar.corner_radius(decorations.corner_radius);
+            }
+            let margin = decorations.map_or(0.0, |d| d.margin);
+            grip = status_bar
+                .grip_size()
+                .map(|size| Size::new(size.width + margin, size.height + margin));
+            column = column.push(status_bar.view(self.maximized));
+        }
+        let content = column;
+
+        // neither is drawn on a maximized window
+        let border = if self.maximized { 0.0 } else { self.border };
+        let content: Element<'a, Message, Renderer> = if decorations.is_some() || border > 0.0 {
+            Frame::new(content, style)
+                .decorations(decorations)
+                .border_width(border)
+                .focused(self.focused)
+                .into()
+        } else {
+            content.into()
+        };
+
+        let content: Element<'a, Message, Renderer> = match self.work_area {
+            Some(work_area) => snap_assist(content, work_area, move |zone, bounds| {
+                (event_handler3)(WindowEvents::Snap(zone, bounds))
+            })
+            .margin(decorations.map_or(0.0, |d| d.margin))
+            .into(),
+            None => content.into(),
+        };
+
+        let mut window = resize(content, move |e| {
+            (event_handler2)(WindowEvents::ResizeEvent(e))
+        })
+        // a maximized window fills the screen, its edges can't be dragged
+        .show(!self.maximized);
+        if let Some(position) = self.position {
+            window = window.initial_position(position);
+        }
+        if let Some(decorations) = decorations {
+            // the resize zones take up the margin around the window content
+            window = window
+                .border(decorations.margin)
+                .corner(2.0 * decorations.margin);
+        }
+        if let Some(grip) = grip {
+            window = window.grip(grip);
+        }
         window.into()
         // // let asdf = theme_handler::ThemeHandler::new(window, );
         // let asdf = theme_handler::new_theme_handler::<'a, Message, Renderer, NewRenderer>(
@@ -100,16 +317,26 @@ impl Window {
 }
 
 impl Window {
-    pub fn event_handler<Message>(event: WindowEvents) -> Command<Message> {
+    /// Produces the command performing an action on the window
+    ///
+    /// iced only addresses the window the application runs in,
+    /// which is the only window a [`Window`] can belong to
+    fn action<Message>(&self, action: window::Action<Message>) -> Command<Message> {
+        Command::single(command::Action::Window(action))
+    }
+
+    /// Handles an event of the chrome of this window
+    pub fn event_handler<Message>(&mut self, event: WindowEvents) -> Command<Message> {
         match event {
             WindowEvents::ResizeEvent(re) => match re {
                 ResizeEvent::ResizeXY(size) => {
-                    return Command::single(command::Action::Window(window::Action::Resize {
+                    return self.action(window::Action::Resize {
                         width: size.width as u32,
                         height: size.height as u32,
-                    }));
+                    });
                 }
                 ResizeEvent::ResizeWindow(rec) => {
+                    self.position = Some(rec.position());
                     let Rectangle {
                         x,
                         y,
@@ -117,35 +344,128 @@ impl Window {
                         height,
                     } = rec;
                     return Command::batch(vec![
-                        Command::single(command::Action::Window(window::Action::Resize {
+                        self.action(window::Action::Resize {
                             width: width as u32,
                             height: height as u32,
-                        })),
-                        Command::single(command::Action::Window(window::Action::Move {
+                        }),
+                        self.action(window::Action::Move {
                             x: x as i32,
                             y: y as i32,
-                        })),
+                        }),
                     ]);
                 }
+                // iced has no window action for interactive resizing yet,
+                // the application has to hand this to the windowing layer itself
+                ResizeEvent::DragResize(_) => return Command::none(),
             },
             WindowEvents::TitleEvent(te) => match te {
                 TitleEvents::Minimize => {
-                    return Command::single(command::Action::Window(window::Action::Minimize(
-                        true,
-                    )));
+                    return self.action(window::Action::Minimize(true));
+                }
+                TitleEvents::Maximize => {
+                    self.maximized = true;
+                    self.maximized_size = None;
+                    return self.action(window::Action::Maximize(true));
                 }
                 TitleEvents::Restore => {
-                    return Command::single(command::Action::Window(window::Action::Minimize(
-                        false,
-                    )));
+                    self.maximized = false;
+                    self.maximized_size = None;
+                    return self.action(window::Action::Maximize(false));
                 }
                 TitleEvents::Close => {
-                    return Command::single(command::Action::Window(window::Action::Close));
+                    return self.action(window::Action::Close);
                 }
             },
-            WindowEvents::DragWindow => {
-                return Command::single(command::Action::Window(window::Action::Drag))
+            WindowEvents::DragWindow => return self.action(window::Action::Drag),
+            WindowEvents::TitleClick => return Command::none(),
+            WindowEvents::TitleDoubleClick => {
+                let title_event = if self.maximized {
+                    TitleEvents::Restore
+                } else {
+                    TitleEvents::Maximize
+                };
+                return self.event_handler(WindowEvents::TitleEvent(title_event));
+            }
+            WindowEvents::Focused(focused) => {
+                self.focused = focused;
+                return Command::none();
+            }
+            WindowEvents::Resized(size) => {
+                // iced reports no maximize events, a window covering the work area
+                // is taken as maximized, without a work area the first size after
+                // maximizing is kept and any other size means it was restored
+                if let Some(work_area) = self.work_area {
+                    self.maximized =
+                        size.width >= work_area.width && size.height >= work_area.height;
+                } else if self.maximized {
+                    match self.maximized_size {
+                        None => self.maximized_size = Some(size),
+                        Some(maximized_size) if maximized_size != size => {
+                            self.maximized = false;
+                            self.maximized_size = None;
+                        }
+                        Some(_) => {}
+                    }
+                }
+                return Command::none();
+            }
+            WindowEvents::Moved(position) => {
+                self.position = Some(position);
+                return Command::none();
+            }
+            WindowEvents::Snap(SnapZone::Maximize, _) => {
+                return self.event_handler(WindowEvents::TitleEvent(TitleEvents::Maximize));
+            }
+            WindowEvents::Snap(_, bounds) => {
+                return self
+                    .event_handler(WindowEvents::ResizeEvent(ResizeEvent::ResizeWindow(bounds)));
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handle(window: &mut Window, event: WindowEvents) {
+        let _: Command<()> = window.event_handler(event);
+    }
+
+    fn resized(width: f32, height: f32) -> WindowEvents {
+        WindowEvents::Resized(Size::new(width, height))
+    }
+
+    #[test]
+    fn resizing_a_maximized_window_restores_it_without_a_work_area() {
+        let mut window = Window::new();
+
+        handle(&mut window, WindowEvents::TitleEvent(TitleEvents::Maximize));
+        assert!(window.is_maximized());
+
+        // the size the window manager maximized the window to
+        handle(&mut window, resized(1920.0, 1050.0));
+        handle(&mut window, resized(1920.0, 1050.0));
+        assert!(window.is_maximized());
+
+        // e.g. dragging the maximized window off the top of the screen
+        handle(&mut window, resized(800.0, 600.0));
+        assert!(!window.is_maximized());
+    }
+
+    #[test]
+    fn work_area_decides_whether_the_window_is_maximized() {
+        let mut window = Window::new().snap_assist(Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 1920.0,
+            height: 1050.0,
+        });
+
+        handle(&mut window, resized(1920.0, 1050.0));
+        assert!(window.is_maximized());
+
+        handle(&mut window, resized(800.0, 600.0));
+        assert!(!window.is_maximized());
+    }
+}
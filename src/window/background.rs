@@ -0,0 +1,202 @@
+//! The background of the title bar and the status bar
+use iced_native::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use super::StyleSheet;
+
+/// The part of the chrome a [`ChromeBackground`] fills
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Region {
+    TitleBar,
+    StatusBar,
+}
+
+/// Fills a bar of the chrome and sets its text color, dimmed when the window isn't focused
+pub(super) struct ChromeBackground<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    region: Region,
+    focused: bool,
+    corner_radius: [f32; 4],
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ChromeBackground<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        region: Region,
+        focused: bool,
+        corner_radius: [f32; 4],
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            region,
+            focused,
+            corner_radius,
+            style,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ChromeBackground<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let (background, text_color) = match (self.region, self.focused) {
+            (Region::TitleBar, true) => {
+                let appearance = theme.title_bar(&self.style);
+                (appearance.background, appearance.title)
+            }
+            (Region::TitleBar, false) => {
+                let appearance = theme.inactive_title_bar(&self.style);
+                (appearance.background, appearance.title)
+            }
+            (Region::StatusBar, true) => {
+                let appearance = theme.status_bar(&self.style);
+                (appearance.background, appearance.text)
+            }
+            (Region::StatusBar, false) => {
+                let appearance = theme.inactive_status_bar(&self.style);
+                (appearance.background, appearance.text)
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: self.corner_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            background,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color,
+                ..*style
+            },
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<ChromeBackground<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: ChromeBackground<'a, Message, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
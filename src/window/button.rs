@@ -0,0 +1,211 @@
+//! The buttons of the [`TitleBar`](super::TitleBar)
+use iced_native::{
+    event, layout, mouse, renderer, svg, touch,
+    widget::{tree, Tree},
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use super::{StyleSheet, TitleButton};
+
+#[derive(Debug, Default)]
+struct ButtonState {
+    is_pressed: bool,
+}
+
+/// A title button drawing its background and tinted icon from the [`StyleSheet`]
+pub(super) struct ChromeButton<Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    kind: TitleButton,
+    handle: svg::Handle,
+    tinted: bool,
+    focused: bool,
+    size: Size,
+    on_press: Option<Message>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> ChromeButton<Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) fn new(kind: TitleButton, handle: svg::Handle, size: Size) -> Self {
+        Self {
+            kind,
+            handle,
+            tinted: true,
+            focused: true,
+            size,
+            on_press: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets whether the icon is tinted, or keeps the colors of the svg
+    pub(super) fn tinted(mut self, tinted: bool) -> Self {
+        self.tinted = tinted;
+        self
+    }
+
+    /// Sets whether the window is focused, buttons of background windows are dimmed
+    pub(super) fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets the message produced by clicking the button, without one it's disabled
+    pub(super) fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    pub(super) fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for ChromeButton<Message, Renderer>
+where
+    Renderer: renderer::Renderer + svg::Renderer,
+    Renderer::Theme: StyleSheet,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        Length::Fixed(self.size.width)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<ButtonState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(ButtonState::default())
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width()).height(self.height());
+
+        layout::Node::new(limits.resolve(self.size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<ButtonState>();
+        let Some(on_press) = &self.on_press else { return event::Status::Ignored };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if layout.bounds().contains(cursor_position) {
+                    state.is_pressed = true;
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if state.is_pressed {
+                    state.is_pressed = false;
+                    if layout.bounds().contains(cursor_position) {
+                        shell.publish(on_press.clone());
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_pressed = false;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<ButtonState>();
+
+        let appearance = if self.on_press.is_none() {
+            theme.disabled(&self.style, self.kind)
+        } else if state.is_pressed {
+            theme.pressed(&self.style, self.kind)
+        } else if bounds.contains(cursor_position) {
+            theme.hovered(&self.style, self.kind)
+        } else if !self.focused {
+            theme.inactive(&self.style, self.kind)
+        } else {
+            theme.active(&self.style, self.kind)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        // the icon is a centered square
+        let side = bounds.width.min(bounds.height);
+        let icon_bounds = Rectangle {
+            x: bounds.center_x() - side / 2.0,
+            y: bounds.center_y() - side / 2.0,
+            width: side,
+            height: side,
+        };
+        let color = appearance.icon.filter(|_| self.tinted);
+        renderer.draw(self.handle.clone(), color, icon_bounds);
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_press.is_some() && layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ChromeButton<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + svg::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: ChromeButton<Message, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
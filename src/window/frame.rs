@@ -0,0 +1,276 @@
+//! Client-side decorations drawn around the window content
+use iced_native::{
+    event, layout, mouse, overlay, renderer,
+    widget::{Operation, Tree},
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use super::StyleSheet;
+
+/// The client-side decorations of a [`Window`](super::Window)
+///
+/// The shadow is drawn into a margin around the window content, the margin
+/// is only see-through when the window is created with a transparent background
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decorations {
+    /// The width of the margin holding the shadow and the resize zones
+    pub margin: f32,
+    /// The radius of the outer corners of the window content
+    pub corner_radius: f32,
+}
+
+impl Default for Decorations {
+    fn default() -> Self {
+        Self {
+            margin: 10.0,
+            corner_radius: 8.0,
+        }
+    }
+}
+
+/// Draws the shadow, the rounded background and the border outline around the content
+pub(super) struct Frame<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    decorations: Option<Decorations>,
+    border_width: f32,
+    focused: bool,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Frame<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    pub(super) fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            decorations: None,
+            border_width: 0.0,
+            focused: true,
+            style,
+        }
+    }
+
+    /// Sets the shadow and rounded corners drawn around the content
+    pub(super) fn decorations(mut self, decorations: Option<Decorations>) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Sets the width of the outline drawn over the edges of the content
+    pub(super) fn border_width(mut self, border_width: f32) -> Self {
+        self.border_width = border_width;
+        self
+    }
+
+    /// Sets whether the window is focused, this picks the color of the outline
+    pub(super) fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Frame<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fill
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let size = limits.width(Length::Fill).height(Length::Fill).max();
+        let margin = self.decorations.map_or(0.0, |d| d.margin);
+
+        let inner = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (size.width - 2.0 * margin).max(0.0),
+                (size.height - 2.0 * margin).max(0.0),
+            ),
+        );
+        let mut content = self.content.as_widget().layout(renderer, &inner);
+        content.move_to(Point::new(margin, margin));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+        let inner = content_layout.bounds();
+        let appearance = if self.focused {
+            theme.frame(&self.style)
+        } else {
+            theme.inactive_frame(&self.style)
+        };
+        let corner_radius = self.decorations.map_or(0.0, |d| d.corner_radius);
+
+        if let Some(Decorations { margin, .. }) = self.decorations {
+            // quads have no blur, so the shadow is stacked from one quad per pixel of margin,
+            // the shadow gets darker where more of them overlap
+            let layers = margin.ceil().max(0.0) as usize;
+            let layer_color = Color {
+                a: appearance.shadow.a / layers.max(1) as f32,
+                ..appearance.shadow
+            };
+            for layer in (1..=layers).rev() {
+                let spread = layer as f32;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: inner.x - spread,
+                            y: inner.y - spread,
+                            width: inner.width + 2.0 * spread,
+                            height: inner.height + 2.0 * spread,
+                        },
+                        border_radius: (corner_radius + spread).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    layer_color,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: inner,
+                    border_radius: corner_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                appearance.background,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        // the outline is drawn over the content, so it takes no space from it
+        if self.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: inner,
+                    border_radius: corner_radius.into(),
+                    border_width: self.border_width,
+                    border_color: appearance.border,
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Frame<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: Frame<'a, Message, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
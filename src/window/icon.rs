@@ -0,0 +1,49 @@
+//! The system menu opened from the window icon of the [`TitleBar`](super::TitleBar)
+use iced_native::{
+    renderer,
+    widget::{button, text},
+    Element, Length,
+};
+
+use super::{TitleEvents, WindowEvents};
+use crate::menu::{self, MenuTree};
+
+/// Builds the system menu opened by clicking the window icon
+///
+/// Items that don't apply to the current window state are disabled.
+/// There are no move and size items, the menu buttons fire once the mouse button
+/// is released and the window manager needs a held button to start a drag
+pub(super) fn system_menu<'a, Message, Renderer, F>(
+    icon: Element<'a, Message, Renderer>,
+    maximized: bool,
+    event_handler: &F,
+) -> MenuTree<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer + iced_native::text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet + text::StyleSheet + menu::StyleSheet,
+    Message: 'a + Clone,
+    F: Fn(WindowEvents) -> Message,
+{
+    let children = system_menu_items(maximized)
+        .into_iter()
+        .map(|(label, enabled, event)| {
+            let mut item = button(text(label)).width(Length::Fill);
+            if enabled {
+                item = item.on_press((event_handler)(WindowEvents::TitleEvent(event)));
+            }
+            MenuTree::new(item).label(label)
+        })
+        .collect();
+
+    MenuTree::with_children(icon, children).label("System menu")
+}
+
+/// The items of the system menu in order, with whether they apply to the window state
+pub(crate) fn system_menu_items(maximized: bool) -> [(&'static str, bool, TitleEvents); 4] {
+    [
+        ("Restore", maximized, TitleEvents::Restore),
+        ("Minimize", true, TitleEvents::Minimize),
+        ("Maximize", !maximized, TitleEvents::Maximize),
+        ("Close", true, TitleEvents::Close),
+    ]
+}
@@ -0,0 +1,202 @@
+//! Title bar layouts that resemble the native ones of the common desktops
+use iced_native::{widget::svg, Size};
+
+use super::TitleButton;
+use crate::svgs;
+
+/// Which title buttons appear on which side of the title bar
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ButtonLayout {
+    /// The buttons before the icon, in order from left to right
+    pub left: Vec<TitleButton>,
+    /// The buttons at the end, in order from left to right
+    pub right: Vec<TitleButton>,
+}
+
+impl ButtonLayout {
+    /// Creates a new [`ButtonLayout`] from the buttons on each side
+    pub fn new(left: Vec<TitleButton>, right: Vec<TitleButton>) -> Self {
+        Self { left, right }
+    }
+
+    /// Parses a GNOME style `button-layout` like `"close:minimize,maximize"`
+    ///
+    /// The buttons before the colon go on the left, the ones after it on the right.
+    /// Names that aren't buttons, like `appmenu` or `icon`, are skipped
+    pub fn parse(layout: &str) -> Self {
+        /// parses the buttons of one side
+        fn side(names: &str) -> Vec<TitleButton> {
+            names
+                .split(',')
+                .filter_map(TitleButton::from_name)
+                .collect()
+        }
+
+        let (left, right) = layout.split_once(':').unwrap_or((layout, ""));
+
+        Self {
+            left: side(left),
+            right: side(right),
+        }
+    }
+}
+
+impl TitleButton {
+    /// The button with the given GNOME `button-layout` name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "minimize" => Some(Self::Minimize),
+            "maximize" => Some(Self::Maximize),
+            "close" => Some(Self::Close),
+            _ => None,
+        }
+    }
+}
+
+/// The look of the title buttons
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ButtonIcons {
+    /// Plain glyphs on rectangular buttons
+    #[default]
+    Windows,
+    /// Red, yellow and green circles
+    TrafficLights,
+    /// Glyphs on a round background
+    Round,
+}
+
+impl ButtonIcons {
+    /// Whether the icons are tinted by the style, traffic lights keep their colors
+    pub fn tinted(self) -> bool {
+        self != Self::TrafficLights
+    }
+
+    /// The svg of a title button
+    pub fn handle(self, button: TitleButton, maximized: bool) -> svg::Handle {
+        match (self, button) {
+            (Self::Windows, TitleButton::Minimize) => svgs::minimize_svg(),
+            (Self::Windows, TitleButton::Maximize) if maximized => svgs::restore(),
+            (Self::Windows, TitleButton::Maximize) => svgs::maximize(),
+            (Self::Windows, TitleButton::Close) => svgs::close_svg(),
+            (Self::TrafficLights, TitleButton::Minimize) => svgs::traffic_minimize(),
+            (Self::TrafficLights, TitleButton::Maximize) => svgs::traffic_maximize(),
+            (Self::TrafficLights, TitleButton::Close) => svgs::traffic_close(),
+            (Self::Round, TitleButton::Minimize) => svgs::round_minimize(),
+            (Self::Round, TitleButton::Maximize) if maximized => svgs::round_restore(),
+            (Self::Round, TitleButton::Maximize) => svgs::round_maximize(),
+            (Self::Round, TitleButton::Close) => svgs::round_close(),
+        }
+    }
+}
+
+/// A title bar resembling the native one of a desktop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// Minimize, maximize and close on the right, on rectangular buttons
+    Windows,
+    /// Traffic lights on the left
+    MacOs,
+    /// A round close button on the right
+    Gnome,
+    /// Minimize, maximize and close on the right, on round buttons
+    Kde,
+}
+
+impl Preset {
+    /// The button layout of the preset
+    pub fn layout(self) -> ButtonLayout {
+        match self {
+            Self::Windows | Self::Kde => ButtonLayout::parse(":minimize,maximize,close"),
+            Self::MacOs => ButtonLayout::parse("close,minimize,maximize:"),
+            Self::Gnome => ButtonLayout::parse("appmenu:close"),
+        }
+    }
+
+    /// The look of the buttons of the preset
+    pub fn icons(self) -> ButtonIcons {
+        match self {
+            Self::Windows => ButtonIcons::Windows,
+            Self::MacOs => ButtonIcons::TrafficLights,
+            Self::Gnome | Self::Kde => ButtonIcons::Round,
+        }
+    }
+
+    /// The size of the buttons of the preset
+    pub fn button_size(self) -> Size {
+        match self {
+            Self::Windows => Size::new(50.0, 30.0),
+            Self::MacOs => Size::new(20.0, 14.0),
+            Self::Gnome | Self::Kde => Size::new(30.0, 20.0),
+        }
+    }
+
+    /// The spacing between the buttons of the preset
+    pub fn spacing(self) -> f32 {
+        match self {
+            Self::Windows => 0.0,
+            Self::MacOs => 2.0,
+            Self::Gnome | Self::Kde => 6.0,
+        }
+    }
+
+    /// The height of the title bar of the preset
+    pub fn height(self) -> f32 {
+        match self {
+            Self::Windows => 35.0,
+            Self::MacOs => 28.0,
+            Self::Gnome => 38.0,
+            Self::Kde => 30.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::TitleButton::{Close, Maximize, Minimize};
+
+    #[test]
+    fn layout_without_colon_is_all_left() {
+        assert_eq!(
+            ButtonLayout::parse("close,minimize"),
+            ButtonLayout::new(vec![Close, Minimize], vec![])
+        );
+        assert_eq!(ButtonLayout::parse(""), ButtonLayout::default());
+    }
+
+    #[test]
+    fn whitespace_around_names_is_ignored() {
+        assert_eq!(
+            ButtonLayout::parse(" close :  minimize , maximize "),
+            ButtonLayout::new(vec![Close], vec![Minimize, Maximize])
+        );
+    }
+
+    #[test]
+    fn unknown_names_are_skipped() {
+        assert_eq!(
+            ButtonLayout::parse("icon,appmenu:spacer,close"),
+            ButtonLayout::new(vec![], vec![Close])
+        );
+        assert_eq!(TitleButton::from_name("appmenu"), None);
+        assert_eq!(TitleButton::from_name("icon"), None);
+    }
+
+    #[test]
+    fn preset_layouts() {
+        let cases = [
+            (Preset::Windows, vec![], vec![Minimize, Maximize, Close]),
+            (Preset::MacOs, vec![Close, Minimize, Maximize], vec![]),
+            (Preset::Gnome, vec![], vec![Close]),
+            (Preset::Kde, vec![], vec![Minimize, Maximize, Close]),
+        ];
+
+        for (preset, left, right) in cases {
+            assert_eq!(
+                preset.layout(),
+                ButtonLayout::new(left, right),
+                "{preset:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,474 @@
+//! Snap the window to halves and quarters of the screen while it's being moved
+//!
+//! The window manager takes over the cursor while the window is dragged,
+//! so the cursor position is estimated from the window position and the point
+//! where the window was grabbed.
+use iced_native::{event, mouse, overlay, touch, Color, Element, Event, Point, Rectangle, Size};
+use iced_native::{
+    renderer,
+    widget::{tree, Operation, Tree},
+    Clipboard, Layout, Shell, Widget,
+};
+
+/// A region of the work area the window can snap to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapZone {
+    /// The whole work area
+    Maximize,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl SnapZone {
+    /// Finds the zone the cursor points at
+    ///
+    /// The cursor has to be within `threshold` of an edge of the work area,
+    /// along the side edges the upper and lower quarters snap to the corners
+    pub fn at(work_area: Rectangle, cursor: Point, threshold: f32) -> Option<Self> {
+        let left = cursor.x - work_area.x;
+        let right = work_area.x + work_area.width - cursor.x;
+        let top = cursor.y - work_area.y;
+        let bottom = work_area.y + work_area.height - cursor.y;
+
+        // the cursor is on another screen
+        if left < -threshold || right < -threshold || top < -threshold || bottom < -threshold {
+            return None;
+        }
+
+        let upper = top < work_area.height / 4.0;
+        let lower = bottom < work_area.height / 4.0;
+
+        let zone = if left < threshold {
+            if upper {
+                Self::TopLeft
+            } else if lower {
+                Self::BottomLeft
+            } else {
+                Self::Left
+            }
+        } else if right < threshold {
+            if upper {
+                Self::TopRight
+            } else if lower {
+                Self::BottomRight
+            } else {
+                Self::Right
+            }
+        } else if top < threshold {
+            Self::Maximize
+        } else {
+            return None;
+        };
+
+        Some(zone)
+    }
+
+    /// The bounds a window snapped to the zone takes up
+    pub fn bounds(self, work_area: Rectangle) -> Rectangle {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = work_area;
+        let half = Size::new(width / 2.0, height / 2.0);
+
+        let (position, size) = match self {
+            Self::Maximize => return work_area,
+            Self::Left => (Point::new(x, y), Size::new(half.width, height)),
+            Self::Right => (Point::new(x + half.width, y), Size::new(half.width, height)),
+            Self::TopLeft => (Point::new(x, y), half),
+            Self::TopRight => (Point::new(x + half.width, y), half),
+            Self::BottomLeft => (Point::new(x, y + half.height), half),
+            Self::BottomRight => (Point::new(x + half.width, y + half.height), half),
+        };
+
+        Rectangle::new(position, size)
+    }
+
+    /// The bounds a window with a transparent `margin` around its visible part
+    /// takes up when it's snapped to the zone, e.g. the shadow of
+    /// [`Decorations`](super::Decorations)
+    ///
+    /// The margin reaches past the zone, so the visible part fills it.
+    /// A maximized window is drawn without the margin
+    pub fn window_bounds(self, work_area: Rectangle, margin: f32) -> Rectangle {
+        let bounds = self.bounds(work_area);
+        if self == Self::Maximize {
+            return bounds;
+        }
+
+        Rectangle {
+            x: bounds.x - margin,
+            y: bounds.y - margin,
+            width: bounds.width + 2.0 * margin,
+            height: bounds.height + 2.0 * margin,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SnapState {
+    /// Where the window was last pressed, in window coordinates
+    grab: Option<Point>,
+    /// Whether the window moved since it was pressed
+    moving: bool,
+    window_position: Point,
+    target: Option<SnapZone>,
+}
+
+pub fn snap_assist<'a, Message, Renderer, F>(
+    element: impl Into<Element<'a, Message, Renderer>>,
+    work_area: Rectangle,
+    on_snap: F,
+) -> SnapAssist<'a, Message, Renderer>
+where
+    F: 'a + Fn(SnapZone, Rectangle) -> Message,
+{
+    SnapAssist::new(element, work_area, on_snap)
+}
+
+/// A widget that previews and reports snapping while its window is moved
+///
+/// When a move ends over a [`SnapZone`] the zone and its bounds are published,
+/// the window is expected to be moved and resized to them
+pub struct SnapAssist<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    work_area: Rectangle,
+    threshold: f32,
+    margin: f32,
+    preview_color: Color,
+    on_snap: Box<dyn Fn(SnapZone, Rectangle) -> Message + 'a>,
+}
+
+impl<'a, Message, Renderer> SnapAssist<'a, Message, Renderer> {
+    /// Creates a new [`SnapAssist`] for a monitor with the given work area
+    pub fn new<F>(
+        element: impl Into<Element<'a, Message, Renderer>>,
+        work_area: Rectangle,
+        on_snap: F,
+    ) -> Self
+    where
+        F: 'a + Fn(SnapZone, Rectangle) -> Message,
+    {
+        Self {
+            element: element.into(),
+            work_area,
+            threshold: 8.0,
+            margin: 0.0,
+            preview_color: Color::from_rgba(0.5, 0.7, 1.0, 0.3),
+            on_snap: Box::new(on_snap),
+        }
+    }
+
+    /// Sets how close to the edges of the work area the cursor has to get
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the transparent margin around the visible part of the window,
+    /// see [`SnapZone::window_bounds`]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the color of the preview of the snap target
+    pub fn preview_color(mut self, preview_color: Color) -> Self {
+        self.preview_color = preview_color;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SnapAssist<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn width(&self) -> iced_native::Length {
+        iced_native::Length::Fill
+    }
+
+    fn height(&self) -> iced_native::Length {
+        iced_native::Length::Fill
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.element)]
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<SnapState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(SnapState::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.element))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.element.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<SnapState>();
+
+        match event {
+            Event::Window(iced_native::window::Event::Moved { x, y }) => {
+                state.window_position = Point::new(x as f32, y as f32);
+
+                if let Some(grab) = state.grab {
+                    let cursor = state.window_position + (grab - Point::ORIGIN);
+
+                    state.moving = true;
+                    state.target = SnapZone::at(self.work_area, cursor, self.threshold);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.grab = Some(cursor_position);
+                state.moving = false;
+                state.target = None;
+            }
+            // the window manager hands the cursor back once the move is over
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::ButtonReleased(_))
+            | Event::Touch(touch::Event::FingerMoved { .. } | touch::Event::FingerLifted { .. }) => {
+                if let Some(zone) = state.target.filter(|_| state.moving) {
+                    let bounds = zone.window_bounds(self.work_area, self.margin);
+                    shell.publish((self.on_snap)(zone, bounds));
+                }
+
+                // a press the cursor moves away from didn't start a move,
+                // e.g. the window is resized, this keeps those moves from snapping
+                let released = !matches!(
+                    event,
+                    Event::Mouse(mouse::Event::CursorMoved { .. })
+                        | Event::Touch(touch::Event::FingerMoved { .. })
+                );
+                let left_grab = state.grab.map_or(false, |grab| {
+                    grab.distance(cursor_position) > self.threshold
+                });
+                if state.moving || released || left_grab {
+                    state.grab = None;
+                }
+
+                state.moving = false;
+                state.target = None;
+            }
+            _ => {}
+        }
+
+        self.element.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &iced_native::layout::Limits,
+    ) -> iced_native::layout::Node {
+        let size = limits.max();
+
+        iced_native::layout::Node::with_children(
+            size,
+            vec![self.element.as_widget().layout(renderer, limits)],
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.element.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<SnapState>();
+        if let Some(zone) = state.target {
+            // only the part of the preview inside the window can be drawn
+            let mut bounds = zone.bounds(self.work_area);
+            bounds.x -= state.window_position.x;
+            bounds.y -= state.window_position.y;
+
+            renderer.with_layer(layout.bounds(), |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    self.preview_color,
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.element.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.element.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<SnapAssist<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(value: SnapAssist<'a, Message, Renderer>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    fn work_area() -> Rectangle {
+        rect(0.0, 0.0, 1600.0, 900.0)
+    }
+
+    fn at(x: f32, y: f32) -> Option<SnapZone> {
+        SnapZone::at(work_area(), Point::new(x, y), 8.0)
+    }
+
+    #[test]
+    fn side_edges_snap_to_halves() {
+        assert_eq!(at(0.0, 450.0), Some(SnapZone::Left));
+        assert_eq!(at(1599.0, 450.0), Some(SnapZone::Right));
+    }
+
+    #[test]
+    fn side_edge_quarters_snap_to_corners() {
+        assert_eq!(at(0.0, 100.0), Some(SnapZone::TopLeft));
+        assert_eq!(at(0.0, 800.0), Some(SnapZone::BottomLeft));
+        assert_eq!(at(1599.0, 100.0), Some(SnapZone::TopRight));
+        assert_eq!(at(1599.0, 800.0), Some(SnapZone::BottomRight));
+    }
+
+    #[test]
+    fn top_edge_maximizes() {
+        assert_eq!(at(800.0, 0.0), Some(SnapZone::Maximize));
+        // the bottom edge has no zone
+        assert_eq!(at(800.0, 899.0), None);
+    }
+
+    #[test]
+    fn threshold_limits_the_zones() {
+        assert_eq!(at(7.9, 450.0), Some(SnapZone::Left));
+        assert_eq!(at(8.0, 450.0), None);
+        assert_eq!(at(800.0, 450.0), None);
+
+        // a bit past the edge still snaps, further out is another screen
+        assert_eq!(at(-5.0, 450.0), Some(SnapZone::Left));
+        assert_eq!(at(-20.0, 450.0), None);
+    }
+
+    #[test]
+    fn bounds_split_the_work_area() {
+        let work_area = rect(100.0, 50.0, 1600.0, 900.0);
+        let cases = [
+            (SnapZone::Maximize, work_area),
+            (SnapZone::Left, rect(100.0, 50.0, 800.0, 900.0)),
+            (SnapZone::Right, rect(900.0, 50.0, 800.0, 900.0)),
+            (SnapZone::TopLeft, rect(100.0, 50.0, 800.0, 450.0)),
+            (SnapZone::TopRight, rect(900.0, 50.0, 800.0, 450.0)),
+            (SnapZone::BottomLeft, rect(100.0, 500.0, 800.0, 450.0)),
+            (SnapZone::BottomRight, rect(900.0, 500.0, 800.0, 450.0)),
+        ];
+
+        for (zone, expected) in cases {
+            assert_eq!(zone.bounds(work_area), expected, "{zone:?}");
+        }
+    }
+
+    #[test]
+    fn window_bounds_reach_past_the_zone_by_the_margin() {
+        assert_eq!(
+            SnapZone::Left.window_bounds(work_area(), 10.0),
+            rect(-10.0, -10.0, 820.0, 920.0)
+        );
+        assert_eq!(
+            SnapZone::Maximize.window_bounds(work_area(), 10.0),
+            work_area()
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! A status bar along the bottom of the [`Window`](super::Window)
+use iced_native::{
+    alignment, layout, renderer,
+    widget::{container, Row, Tree},
+    Alignment, Color, Element, Layout, Length, Padding, Point, Rectangle, Size, Widget,
+};
+
+use super::{
+    background::{ChromeBackground, Region},
+    StyleSheet,
+};
+
+/// A builder for the status bar of a [`Window`](super::Window)
+///
+/// The status bar has a left, a center and a right section,
+/// followed by a resize grip in the bottom right corner of the window
+#[allow(missing_debug_implementations)]
+pub struct StatusBar<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    height: f32,
+    padding: Padding,
+    spacing: f32,
+    left: Vec<Element<'a, Message, Renderer>>,
+    center: Vec<Element<'a, Message, Renderer>>,
+    right: Vec<Element<'a, Message, Renderer>>,
+    grip: bool,
+    focused: bool,
+    corner_radius: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Default for StatusBar<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> StatusBar<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new empty [`StatusBar`] with a resize grip
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            height: 24.0,
+            padding: Padding::ZERO,
+            spacing: 8.0,
+            left: Vec::new(),
+            center: Vec::new(),
+            right: Vec::new(),
+            grip: true,
+            focused: true,
+            corner_radius: 0.0,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the height of the [`StatusBar`], the resize grip is a square
+    /// as high as the status bar without its padding
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the [`StatusBar`]
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the elements of a section
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Adds an element to the left section
+    #[must_use]
+    pub fn push_left(mut self, element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.left.push(element.into());
+        self
+    }
+
+    /// Adds an element to the center section
+    #[must_use]
+    pub fn push_center(mut self, element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.center.push(element.into());
+        self
+    }
+
+    /// Adds an element to the right section
+    #[must_use]
+    pub fn push_right(mut self, element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.right.push(element.into());
+        self
+    }
+
+    /// Sets whether the resize grip is shown in the bottom right corner
+    #[must_use]
+    pub fn grip(mut self, grip: bool) -> Self {
+        self.grip = grip;
+        self
+    }
+
+    /// Sets the style of the [`StatusBar`]
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the window is focused, the status bar of a background window is dimmed
+    #[must_use]
+    pub(super) fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets the radius of the bottom corners, to match rounded window corners
+    #[must_use]
+    pub(super) fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// The side of the square the resize grip is drawn in
+    fn grip_side(&self) -> f32 {
+        (self.height - self.padding.vertical()).max(0.0)
+    }
+
+    /// The area from the bottom right corner of the status bar to the far edges
+    /// of the resize grip, if it's shown, the padding keeps the grip off the corner
+    pub(super) fn grip_size(&self) -> Option<Size> {
+        let side = self.grip_side();
+        self.grip
+            .then(|| Size::new(side + self.padding.right, side + self.padding.bottom))
+    }
+}
+
+impl<'a, Message, Renderer> StatusBar<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Renderer::Theme: StyleSheet + container::StyleSheet,
+{
+    /// Builds the status bar, the grip is left out while the window is maximized
+    ///
+    /// The grip only draws itself, [`Resize`](crate::resize::Resize) handles the drag
+    pub(super) fn view(self, maximized: bool) -> Element<'a, Message, Renderer> {
+        let section = |elements: Vec<Element<'a, Message, Renderer>>| {
+            Row::with_children(elements)
+                .spacing(self.spacing)
+                .align_items(Alignment::Center)
+        };
+
+        // the sides share the remaining space equally, so the center section stays centered
+        let mut bar = Row::new()
+            .height(Length::Fill)
+            .align_items(Alignment::Center)
+            .push(
+                container(section(self.left))
+                    .width(Length::Fill)
+                    .align_x(alignment::Horizontal::Left),
+            )
+            .push(section(self.center))
+            .push(
+                container(section(self.right))
+                    .width(Length::Fill)
+                    .align_x(alignment::Horizontal::Right),
+            );
+        if self.grip && !maximized {
+            let side = self.grip_side();
+            bar = bar.push(Grip::new(side, self.focused, self.style.clone()));
+        }
+
+        let bar = container(bar)
+            .width(Length::Fill)
+            .height(self.height)
+            .padding(self.padding);
+
+        // only the bottom corners meet the corners of the window
+        let radius = self.corner_radius;
+        ChromeBackground::new(
+            bar,
+            Region::StatusBar,
+            self.focused,
+            [0.0, 0.0, radius, radius],
+            self.style,
+        )
+        .into()
+    }
+}
+
+/// Draws the dots of the resize grip
+struct Grip<Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    size: f32,
+    focused: bool,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> Grip<Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn new(size: f32, focused: bool, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        Self {
+            size,
+            focused,
+            style,
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Grip<Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Fixed(self.size)
+    }
+
+    fn layout(&self, _renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits
+            .width(Length::Fixed(self.size))
+            .height(Length::Fixed(self.size));
+
+        layout::Node::new(limits.resolve(Size::new(self.size, self.size)))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = if self.focused {
+            theme.status_bar(&self.style)
+        } else {
+            theme.inactive_status_bar(&self.style)
+        };
+        let bounds = layout.bounds();
+        let dot = 2.0;
+        let step = 4.0;
+        let inset = 4.0;
+
+        // a triangle of dots pointing into the bottom right corner
+        for row in 0..3 {
+            for column in (2 - row)..3 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + bounds.width - inset - dot - (2 - column) as f32 * step,
+                            y: bounds.y + bounds.height - inset - dot - (2 - row) as f32 * step,
+                            width: dot,
+                            height: dot,
+                        },
+                        border_radius: (dot / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.grip,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Grip<Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(value: Grip<Renderer>) -> Self {
+        Self::new(value)
+    }
+}
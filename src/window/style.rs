@@ -0,0 +1,346 @@
+//! Change the appearance of the window chrome.
+use std::rc::Rc;
+
+use iced_native::Color;
+use iced_style::Theme;
+
+use super::TitleButton;
+
+/// The appearance of a title button.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The background [`Color`] of the button.
+    pub background: Color,
+    /// The border radius of the button.
+    pub border_radius: f32,
+    /// The [`Color`] the icon is tinted with, `None` keeps the colors of the svg.
+    pub icon: Option<Color>,
+}
+impl std::default::Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT,
+            border_radius: 0.0,
+            icon: Some(Color::BLACK),
+        }
+    }
+}
+
+/// The appearance of the title bar.
+#[derive(Debug, Clone, Copy)]
+pub struct TitleBarAppearance {
+    /// The background [`Color`] of the title bar.
+    pub background: Color,
+    /// The [`Color`] of the title.
+    pub title: Color,
+}
+impl std::default::Default for TitleBarAppearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT,
+            title: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of the status bar.
+#[derive(Debug, Clone, Copy)]
+pub struct StatusBarAppearance {
+    /// The background [`Color`] of the status bar.
+    pub background: Color,
+    /// The [`Color`] of the text in the status bar.
+    pub text: Color,
+    /// The [`Color`] of the resize grip.
+    pub grip: Color,
+}
+impl std::default::Default for StatusBarAppearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT,
+            text: Color::BLACK,
+            grip: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+        }
+    }
+}
+
+/// The appearance of the client-side decorations.
+#[derive(Debug, Clone, Copy)]
+pub struct FrameAppearance {
+    /// The background [`Color`] of the window content.
+    pub background: Color,
+    /// The [`Color`] of the shadow where it's darkest.
+    pub shadow: Color,
+    /// The [`Color`] of the outline around the window.
+    pub border: Color,
+}
+impl std::default::Default for FrameAppearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE,
+            shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+            border: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+        }
+    }
+}
+
+/// The style sheet of the window chrome.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`], it's shared by all parts of the chrome.
+    type Style: Default + Clone;
+
+    /// Produces the [`FrameAppearance`] of the client-side decorations.
+    fn frame(&self, style: &Self::Style) -> FrameAppearance;
+
+    /// Produces the [`FrameAppearance`] of the client-side decorations of a window in the background.
+    fn inactive_frame(&self, style: &Self::Style) -> FrameAppearance {
+        let active = self.frame(style);
+
+        FrameAppearance {
+            shadow: Color {
+                a: active.shadow.a * 0.6,
+                ..active.shadow
+            },
+            border: Color {
+                a: active.border.a * 0.6,
+                ..active.border
+            },
+            ..active
+        }
+    }
+
+    /// Produces the [`TitleBarAppearance`] of the title bar of a focused window.
+    fn title_bar(&self, style: &Self::Style) -> TitleBarAppearance;
+
+    /// Produces the [`TitleBarAppearance`] of the title bar of a window in the background.
+    fn inactive_title_bar(&self, style: &Self::Style) -> TitleBarAppearance {
+        let active = self.title_bar(style);
+
+        TitleBarAppearance {
+            title: Color {
+                a: active.title.a * 0.6,
+                ..active.title
+            },
+            ..active
+        }
+    }
+
+    /// Produces the [`StatusBarAppearance`] of the status bar of a focused window.
+    fn status_bar(&self, style: &Self::Style) -> StatusBarAppearance {
+        let title_bar = self.title_bar(style);
+
+        StatusBarAppearance {
+            background: title_bar.background,
+            text: title_bar.title,
+            grip: Color {
+                a: title_bar.title.a * 0.5,
+                ..title_bar.title
+            },
+        }
+    }
+
+    /// Produces the [`StatusBarAppearance`] of the status bar of a window in the background.
+    fn inactive_status_bar(&self, style: &Self::Style) -> StatusBarAppearance {
+        let active = self.status_bar(style);
+
+        StatusBarAppearance {
+            text: Color {
+                a: active.text.a * 0.6,
+                ..active.text
+            },
+            grip: Color {
+                a: active.grip.a * 0.6,
+                ..active.grip
+            },
+            ..active
+        }
+    }
+
+    /// Produces the active [`Appearance`] of a title button.
+    fn active(&self, style: &Self::Style, button: TitleButton) -> Appearance;
+
+    /// Produces the hovered [`Appearance`] of a title button.
+    fn hovered(&self, style: &Self::Style, button: TitleButton) -> Appearance;
+
+    /// Produces the pressed [`Appearance`] of a title button.
+    fn pressed(&self, style: &Self::Style, button: TitleButton) -> Appearance;
+
+    /// Produces the [`Appearance`] of a title button of a window in the background.
+    fn inactive(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let active = self.active(style, button);
+
+        Appearance {
+            icon: active.icon.map(|icon| Color {
+                a: icon.a * 0.6,
+                ..icon
+            }),
+            ..active
+        }
+    }
+
+    /// Produces the disabled [`Appearance`] of a title button.
+    fn disabled(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let active = self.active(style, button);
+
+        Appearance {
+            icon: active.icon.map(|icon| Color {
+                a: icon.a * 0.5,
+                ..icon
+            }),
+            ..active
+        }
+    }
+}
+
+/// The style of the window chrome
+#[derive(Default, Clone)]
+#[allow(missing_debug_implementations)]
+pub enum WindowStyle {
+    /// The default style.
+    #[default]
+    Default,
+    /// A [`Theme`] that uses a [`Custom`] style sheet.
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StyleSheet for Theme {
+    type Style = WindowStyle;
+
+    fn frame(&self, style: &Self::Style) -> FrameAppearance {
+        let palette = self.extended_palette();
+
+        match style {
+            WindowStyle::Default => FrameAppearance {
+                background: palette.background.base.color,
+                shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.35),
+                border: palette.background.strong.color,
+            },
+            WindowStyle::Custom(c) => c.frame(self),
+        }
+    }
+
+    fn inactive_frame(&self, style: &Self::Style) -> FrameAppearance {
+        let palette = self.extended_palette();
+        let active = self.frame(style);
+
+        match style {
+            WindowStyle::Default => FrameAppearance {
+                shadow: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+                border: palette.background.weak.color,
+                ..active
+            },
+            WindowStyle::Custom(c) => c.inactive_frame(self),
+        }
+    }
+
+    fn title_bar(&self, style: &Self::Style) -> TitleBarAppearance {
+        let palette = self.extended_palette();
+
+        match style {
+            WindowStyle::Default => TitleBarAppearance {
+                background: palette.background.base.color,
+                title: palette.background.base.text,
+            },
+            WindowStyle::Custom(c) => c.title_bar(self),
+        }
+    }
+
+    fn inactive_title_bar(&self, style: &Self::Style) -> TitleBarAppearance {
+        let palette = self.extended_palette();
+
+        match style {
+            WindowStyle::Default => TitleBarAppearance {
+                background: palette.background.base.color,
+                title: palette.background.strong.color,
+            },
+            WindowStyle::Custom(c) => c.inactive_title_bar(self),
+        }
+    }
+
+    fn status_bar(&self, style: &Self::Style) -> StatusBarAppearance {
+        let palette = self.extended_palette();
+
+        match style {
+            WindowStyle::Default => StatusBarAppearance {
+                background: palette.background.weak.color,
+                text: palette.background.weak.text,
+                grip: palette.background.strong.color,
+            },
+            WindowStyle::Custom(c) => c.status_bar(self),
+        }
+    }
+
+    fn inactive_status_bar(&self, style: &Self::Style) -> StatusBarAppearance {
+        let palette = self.extended_palette();
+        let active = self.status_bar(style);
+
+        match style {
+            WindowStyle::Default => StatusBarAppearance {
+                text: palette.background.strong.color,
+                ..active
+            },
+            WindowStyle::Custom(c) => c.inactive_status_bar(self),
+        }
+    }
+
+    fn active(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            WindowStyle::Default => Appearance {
+                background: Color::TRANSPARENT,
+                border_radius: 0.0,
+                icon: Some(palette.background.base.text),
+            },
+            WindowStyle::Custom(c) => c.active(self, button),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style, button);
+
+        match (style, button) {
+            (WindowStyle::Default, TitleButton::Close) => Appearance {
+                background: Color::from_rgb8(196, 43, 28),
+                icon: Some(Color::WHITE),
+                ..active
+            },
+            (WindowStyle::Default, _) => Appearance {
+                background: palette.background.weak.color,
+                ..active
+            },
+            (WindowStyle::Custom(c), _) => c.hovered(self, button),
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style, button);
+
+        match (style, button) {
+            (WindowStyle::Default, TitleButton::Close) => Appearance {
+                background: Color::from_rgb8(241, 111, 122),
+                icon: Some(Color::WHITE),
+                ..active
+            },
+            (WindowStyle::Default, _) => Appearance {
+                background: palette.background.strong.color,
+                ..active
+            },
+            (WindowStyle::Custom(c), _) => c.pressed(self, button),
+        }
+    }
+
+    fn inactive(&self, style: &Self::Style, button: TitleButton) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style, button);
+
+        match style {
+            WindowStyle::Default => Appearance {
+                icon: Some(palette.background.strong.color),
+                ..active
+            },
+            WindowStyle::Custom(c) => c.inactive(self, button),
+        }
+    }
+}
@@ -0,0 +1,499 @@
+//! A configurable title bar for the [`Window`](super::Window)
+use std::time::Duration;
+
+use iced_graphics::Color;
+use iced_native::{
+    renderer,
+    widget::{container, svg, text, Id, Image, Row, Svg},
+    Alignment, Element, Length, Padding, Size,
+};
+
+use super::{
+    background::{ChromeBackground, Region},
+    button::ChromeButton,
+    icon::system_menu,
+    ButtonIcons, ButtonLayout, Preset, StyleSheet, TitleEvents, WindowEvents,
+};
+use crate::{
+    accessibility::{self, Node, OpenPath},
+    drag_window::{DragWindow, TitleAlignment},
+    menu::{self, MenuBar, MenuTree},
+};
+
+/// A button of the title bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleButton {
+    Minimize,
+    /// Maximizes the window, or restores it when it's maximized
+    Maximize,
+    Close,
+}
+
+/// A builder for the title bar of a [`Window`](super::Window)
+///
+/// From left to right the title bar holds the left buttons, the icon, the menu bar,
+/// the left widgets, the draggable title area, the right widgets and the right buttons
+#[allow(missing_debug_implementations)]
+pub struct TitleBar<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer + iced_native::text::Renderer,
+    Renderer::Theme: menu::StyleSheet + StyleSheet,
+{
+    height: f32,
+    padding: Padding,
+    spacing: f32,
+    button_size: Size,
+    buttons: ButtonLayout,
+    icons: ButtonIcons,
+    icon: Option<Element<'a, Message, Renderer>>,
+    system_menu: bool,
+    left: Vec<Element<'a, Message, Renderer>>,
+    right: Vec<Element<'a, Message, Renderer>>,
+    menu_bar: Option<MenuBar<'a, Message, Renderer>>,
+    title: Option<&'a str>,
+    title_color: Option<Color>,
+    title_alignment: TitleAlignment,
+    title_font: Renderer::Font,
+    title_size: Option<f32>,
+    subtitle: Option<&'a str>,
+    modified: bool,
+    double_click_interval: Duration,
+    double_click_distance: f32,
+    drag_threshold: f32,
+    focused: bool,
+    corner_radius: f32,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Default for TitleBar<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer + iced_native::text::Renderer,
+    Renderer::Theme: menu::StyleSheet + StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> TitleBar<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer + iced_native::text::Renderer,
+    Renderer::Theme: menu::StyleSheet + StyleSheet,
+{
+    /// Creates a new [`TitleBar`] with the minimize, maximize and close buttons
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            height: 35.0,
+            padding: Padding::ZERO,
+            spacing: 0.0,
+            button_size: Size::new(50.0, 30.0),
+            buttons: Preset::Windows.layout(),
+            icons: ButtonIcons::Windows,
+            icon: None,
+            system_menu: false,
+            left: Vec::new(),
+            right: Vec::new(),
+            menu_bar: None,
+            title: None,
+            title_color: None,
+            title_alignment: TitleAlignment::default(),
+            title_font: Renderer::Font::default(),
+            title_size: None,
+            subtitle: None,
+            modified: false,
+            double_click_interval: Duration::from_millis(500),
+            double_click_distance: 4.0,
+            drag_threshold: 4.0,
+            focused: true,
+            corner_radius: 0.0,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the height of the [`TitleBar`]
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the [`TitleBar`]
+    #[must_use]
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the elements of the [`TitleBar`]
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the size of the title buttons
+    #[must_use]
+    pub fn button_size(mut self, button_size: Size) -> Self {
+        self.button_size = button_size;
+        self
+    }
+
+    /// Sets which title buttons appear at the end, in order from left to right
+    #[must_use]
+    pub fn buttons(mut self, buttons: Vec<TitleButton>) -> Self {
+        self.buttons = ButtonLayout::new(Vec::new(), buttons);
+        self
+    }
+
+    /// Sets which title buttons appear on which side
+    #[must_use]
+    pub fn button_layout(mut self, button_layout: ButtonLayout) -> Self {
+        self.buttons = button_layout;
+        self
+    }
+
+    /// Sets the look of the title buttons
+    #[must_use]
+    pub fn icons(mut self, icons: ButtonIcons) -> Self {
+        self.icons = icons;
+        self
+    }
+
+    /// Sets where the title is placed
+    #[must_use]
+    pub fn title_alignment(mut self, title_alignment: TitleAlignment) -> Self {
+        self.title_alignment = title_alignment;
+        self
+    }
+
+    /// Sets the font of the title
+    #[must_use]
+    pub fn title_font(mut self, title_font: Renderer::Font) -> Self {
+        self.title_font = title_font;
+        self
+    }
+
+    /// Sets the text size of the title
+    #[must_use]
+    pub fn title_size(mut self, title_size: f32) -> Self {
+        self.title_size = Some(title_size);
+        self
+    }
+
+    /// Sets a dimmer text shown after the title, like the path of the open file
+    #[must_use]
+    pub fn subtitle(mut self, subtitle: &'a str) -> Self {
+        self.subtitle = Some(subtitle);
+        self
+    }
+
+    /// Sets whether a dot after the title marks unsaved changes
+    #[must_use]
+    pub fn modified(mut self, modified: bool) -> Self {
+        self.modified = modified;
+        self
+    }
+
+    /// Sets the longest time between two clicks on the title that still toggles maximize
+    #[must_use]
+    pub fn double_click_interval(mut self, interval: Duration) -> Self {
+        self.double_click_interval = interval;
+        self
+    }
+
+    /// Sets how far apart two clicks on the title can be and still toggle maximize
+    #[must_use]
+    pub fn double_click_distance(mut self, distance: f32) -> Self {
+        self.double_click_distance = distance;
+        self
+    }
+
+    /// Sets how far the cursor has to move while pressing the title to move the window
+    #[must_use]
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = threshold;
+        self
+    }
+
+    /// Sets the style of the title bar and its buttons
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the radius of the top corners, to match rounded window corners
+    #[must_use]
+    pub fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// The style of the title bar and its buttons
+    pub(super) fn chrome_style(&self) -> <Renderer::Theme as StyleSheet>::Style {
+        self.style.clone()
+    }
+
+    /// Sets whether the window is focused, the title bar of a background window is dimmed
+    #[must_use]
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets the button layout, button look and sizes of a [`Preset`]
+    #[must_use]
+    pub fn preset(self, preset: Preset) -> Self {
+        self.button_layout(preset.layout())
+            .icons(preset.icons())
+            .button_size(preset.button_size())
+            .spacing(preset.spacing())
+            .height(preset.height())
+    }
+
+    /// Sets the icon at the start of the [`TitleBar`]
+    ///
+    /// Pressing the icon moves the window, unless it opens the system menu
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets an svg as the icon, sized to fit the buttons
+    #[must_use]
+    pub fn svg_icon(self, handle: svg::Handle) -> Self
+    where
+        Renderer: iced_native::svg::Renderer + 'a,
+        Renderer::Theme: iced_native::widget::svg::StyleSheet,
+    {
+        let size = self.button_size.height;
+        self.icon(Svg::new(handle).width(size).height(size))
+    }
+
+    /// Sets a raster image as the icon, sized to fit the buttons
+    #[must_use]
+    pub fn image_icon(self, handle: iced_native::image::Handle) -> Self
+    where
+        Renderer: iced_native::image::Renderer<Handle = iced_native::image::Handle> + 'a,
+    {
+        let size = self.button_size.height;
+        self.icon(Image::new(handle).width(size).height(size))
+    }
+
+    /// Sets whether clicking the icon opens the system menu
+    /// with the restore, minimize, maximize and close items
+    #[must_use]
+    pub fn system_menu(mut self, system_menu: bool) -> Self {
+        self.system_menu = system_menu;
+        self
+    }
+
+    /// Adds a widget between the menu bar and the title
+    #[must_use]
+    pub fn push_left(mut self, element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.left.push(element.into());
+        self
+    }
+
+    /// Adds a widget between the title and the buttons
+    #[must_use]
+    pub fn push_right(mut self, element: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        self.right.push(element.into());
+        self
+    }
+
+    /// Sets the menu roots, the menus stay open when the cursor leaves them
+    #[must_use]
+    pub fn menu_roots(mut self, menu_roots: Vec<MenuTree<'a, Message, Renderer>>) -> Self {
+        self.menu_bar = Some(
+            MenuBar::new(menu_roots)
+                .close_condition(menu::CloseCondition {
+                    leave: false,
+                    click_outside: true,
+                    click_inside: true,
+                })
+                .id(accessibility::menu_bar_id()),
+        );
+        self
+    }
+
+    /// Sets a fully configured [`MenuBar`]
+    #[must_use]
+    pub fn menu_bar(mut self, menu_bar: MenuBar<'a, Message, Renderer>) -> Self {
+        self.menu_bar = Some(menu_bar);
+        self
+    }
+
+    /// Sets the title
+    #[must_use]
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the color of the title
+    #[must_use]
+    pub fn title_color(mut self, title_color: Color) -> Self {
+        self.title_color = Some(title_color);
+        self
+    }
+
+    /// Describes the title bar with the icon, menus and buttons it shows
+    /// to assistive technologies
+    ///
+    /// `open_menus` are the paths collected by [`accessibility::open_menus`],
+    /// the menu bar and the system menu pick their own path by the [`Id`] of their menu bar
+    pub fn accessibility(&self, maximized: bool, open_menus: &[OpenPath]) -> Node {
+        let open_path = |id: Option<&Id>| {
+            open_menus
+                .iter()
+                .find(|(menu_bar, _)| menu_bar.as_ref() == id)
+                .map(|(_, path)| path.as_slice())
+        };
+
+        let icon = self.icon.as_ref().map(|_| {
+            if self.system_menu {
+                let id = accessibility::system_menu_id();
+                accessibility::system_menu(maximized, open_path(Some(&id)))
+            } else {
+                accessibility::window_icon()
+            }
+        });
+        let menu_bar = self.menu_bar.as_ref().map(|menu_bar| {
+            accessibility::menu_bar(menu_bar.menu_roots(), open_path(menu_bar.widget_id()))
+        });
+
+        accessibility::title_bar(
+            self.title,
+            self.subtitle,
+            maximized,
+            &self.buttons,
+            icon,
+            menu_bar,
+        )
+    }
+
+    /// Builds the [`TitleBar`], the maximize button turns into a restore button
+    /// when the window is maximized
+    pub fn view<F>(self, maximized: bool, event_handler: F) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_native::svg::Renderer + 'a,
+        Renderer::Theme: iced_native::widget::container::StyleSheet
+            + iced_native::widget::button::StyleSheet
+            + iced_native::widget::svg::StyleSheet
+            + text::StyleSheet,
+        Message: 'a + Clone,
+        F: Fn(WindowEvents) -> Message,
+    {
+        let mut bar = Row::new()
+            .height(Length::Fill)
+            .spacing(self.spacing)
+            .align_items(Alignment::Center);
+
+        let button_for = |kind| {
+            let handle = self.icons.handle(kind, maximized);
+            title_button(kind, handle, maximized, self.button_size, &event_handler)
+                .tinted(self.icons.tinted())
+                .focused(self.focused)
+                .style(self.style.clone())
+        };
+
+        for kind in self.buttons.left.iter().copied() {
+            bar = bar.push(button_for(kind));
+        }
+
+        match self.icon {
+            Some(icon) if self.system_menu => {
+                let root = system_menu(icon, maximized, &event_handler);
+                bar = bar.push(
+                    MenuBar::new(vec![root])
+                        .close_condition(menu::CloseCondition {
+                            leave: false,
+                            click_outside: true,
+                            click_inside: true,
+                        })
+                        .id(accessibility::system_menu_id()),
+                );
+            }
+            Some(icon) => {
+                let message = (event_handler)(WindowEvents::DragWindow);
+                bar = bar.push(
+                    DragWindow::with_content(icon, message).drag_threshold(self.drag_threshold),
+                );
+            }
+            None => {}
+        }
+        if let Some(menu_bar) = self.menu_bar {
+            bar = bar.push(menu_bar);
+        }
+        for element in self.left {
+            bar = bar.push(element);
+        }
+
+        let mut title =
+            DragWindow::with_width(Length::Fill, (event_handler)(WindowEvents::DragWindow))
+                .set_title(self.title)
+                .set_title_color(self.title_color)
+                .title_alignment(self.title_alignment)
+                .title_font(self.title_font)
+                .set_subtitle(self.subtitle)
+                .modified(self.modified)
+                .on_click((event_handler)(WindowEvents::TitleClick))
+                .on_double_click((event_handler)(WindowEvents::TitleDoubleClick))
+                .double_click_interval(self.double_click_interval)
+                .double_click_distance(self.double_click_distance)
+                .drag_threshold(self.drag_threshold);
+        if let Some(title_size) = self.title_size {
+            title = title.title_size(title_size);
+        }
+        bar = bar.push(title);
+
+        for element in self.right {
+            bar = bar.push(element);
+        }
+        for kind in self.buttons.right.iter().copied() {
+            bar = bar.push(button_for(kind));
+        }
+
+        let bar = container(bar)
+            .width(Length::Fill)
+            .height(self.height)
+            .padding(self.padding);
+
+        // only the top corners meet the corners of the window
+        let radius = self.corner_radius;
+        ChromeBackground::new(
+            bar,
+            Region::TitleBar,
+            self.focused,
+            [radius, radius, 0.0, 0.0],
+            self.style,
+        )
+        .into()
+    }
+}
+
+/// Creates the button for a [`TitleButton`]
+fn title_button<Message, Renderer, F>(
+    kind: TitleButton,
+    handle: svg::Handle,
+    maximized: bool,
+    size: Size,
+    event_handler: &F,
+) -> ChromeButton<Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+    F: Fn(WindowEvents) -> Message,
+{
+    let event = match kind {
+        TitleButton::Minimize => TitleEvents::Minimize,
+        TitleButton::Maximize if maximized => TitleEvents::Restore,
+        TitleButton::Maximize => TitleEvents::Maximize,
+        TitleButton::Close => TitleEvents::Close,
+    };
+
+    ChromeButton::new(kind, handle, size).on_press((event_handler)(WindowEvents::TitleEvent(event)))
+}